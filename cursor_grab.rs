@@ -0,0 +1,119 @@
+
+use winit::{
+    event::DeviceEvent,
+    window::Window
+};
+
+/// Captures and Hides the Cursor for First-Person Controls,
+/// Accumulating Raw Mouse Motion While Captured.
+///
+/// Motion comes from `DeviceEvent::MouseMotion` rather than `CursorMoved`,
+/// since a captured cursor stops moving at the window's edge.
+///
+/// How the cursor is held depends on the platform: it is confined to the
+/// window on Windows and X11, locked in place on macOS, and may not be
+/// capturable at all elsewhere. Motion is still reported if capture fails.
+#[derive(Default)]
+pub struct CursorGrab {
+    grabbed: bool,
+
+    // Motion Since the Last `take_delta`, in Unscaled Device Units
+    delta: [f64; 2]
+}
+
+impl CursorGrab {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Capture and Hide the Cursor, or Release and Show It.
+    pub fn set_grabbed(&mut self, window: &Window, grabbed: bool) {
+        apply_grab(window, grabbed);
+        self.update_grab_state(grabbed);
+    }
+
+    /// Track a Grab or Release Without Touching the Window.
+    /// Motion from before the change is discarded.
+    fn update_grab_state(&mut self, grabbed: bool) {
+        self.grabbed = grabbed;
+        self.delta = [0.0, 0.0];
+    }
+
+    pub fn is_grabbed(&self) -> bool {
+        self.grabbed
+    }
+
+    /// Accumulate Mouse Motion from a Device Event While Grabbed.
+    pub fn handle_device_event(&mut self, event: &DeviceEvent) {
+        if let (true, DeviceEvent::MouseMotion { delta: (x, y) }) = (self.grabbed, event) {
+            self.delta[0] += x;
+            self.delta[1] += y;
+        }
+    }
+
+    /// Get the Mouse Motion Since the Last Call and Reset It.
+    /// Positive Y is Downward.
+    pub fn take_delta(&mut self) -> [f64; 2] {
+        std::mem::replace(&mut self.delta, [0.0, 0.0])
+    }
+}
+
+/// Capture and Hide the Window's Cursor, or Release and Show It.
+fn apply_grab(window: &Window, grabbed: bool) {
+    if let Err(error) = window.set_cursor_grab(grabbed) {
+        eprintln!("Warning: failed to {} cursor: {}",
+            if grabbed { "grab" } else { "release" }, error);
+    }
+
+    window.set_cursor_visible(!grabbed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn motion(x: f64, y: f64) -> DeviceEvent {
+        DeviceEvent::MouseMotion { delta: (x, y) }
+    }
+
+    #[test]
+    fn motion_is_ignored_while_released() {
+        let mut grab = CursorGrab::new();
+        grab.handle_device_event(&motion(3.0, 4.0));
+
+        assert_eq!(grab.take_delta(), [0.0, 0.0]);
+    }
+
+    #[test]
+    fn motion_accumulates_while_grabbed() {
+        let mut grab = CursorGrab::new();
+        grab.update_grab_state(true);
+
+        grab.handle_device_event(&motion(3.0, 4.0));
+        grab.handle_device_event(&motion(-1.0, 2.5));
+        grab.handle_device_event(&DeviceEvent::Added);
+
+        assert_eq!(grab.take_delta(), [2.0, 6.5]);
+    }
+
+    #[test]
+    fn take_delta_resets_motion() {
+        let mut grab = CursorGrab::new();
+        grab.update_grab_state(true);
+        grab.handle_device_event(&motion(3.0, 4.0));
+
+        grab.take_delta();
+        assert_eq!(grab.take_delta(), [0.0, 0.0]);
+    }
+
+    #[test]
+    fn releasing_discards_motion() {
+        let mut grab = CursorGrab::new();
+        grab.update_grab_state(true);
+        grab.handle_device_event(&motion(3.0, 4.0));
+
+        grab.update_grab_state(false);
+        assert!(!grab.is_grabbed());
+        assert_eq!(grab.take_delta(), [0.0, 0.0]);
+    }
+}
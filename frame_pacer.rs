@@ -0,0 +1,160 @@
+
+use std::time::{Duration, Instant};
+
+use winit::window::Window;
+
+/// Refresh Rate Assumed When the Monitor Doesn't Report One.
+const DEFAULT_REFRESH_RATE: f64 = 60.0;
+
+/// Schedules Frames to Match the Display's Refresh Rate.
+pub struct FramePacer {
+    detected_refresh_rate: Option<f64>,
+    refresh_rate_override: Option<f64>,
+    next_frame: Instant
+}
+
+impl FramePacer {
+    pub fn new(window: &Window) -> Self {
+        Self {
+            detected_refresh_rate: detect_refresh_rate(window),
+            refresh_rate_override: None,
+            next_frame: Instant::now()
+        }
+    }
+
+    /// Re-Detect the Refresh Rate from the Monitor the Window is On.
+    /// Call When the Window Moves Between Monitors.
+    pub fn update_monitor(&mut self, window: &Window) {
+        self.detected_refresh_rate = detect_refresh_rate(window);
+    }
+
+    /// Get the Refresh Rate in Hz Frames are Paced to.
+    /// An Override Takes Precedence over the Detected Rate.
+    pub fn refresh_rate(&self) -> f64 {
+        self.refresh_rate_override
+            .or(self.detected_refresh_rate)
+            .unwrap_or(DEFAULT_REFRESH_RATE)
+    }
+
+    /// Get the Refresh Rate Reported by the Window's Monitor, if Any.
+    pub fn detected_refresh_rate(&self) -> Option<f64> {
+        self.detected_refresh_rate
+    }
+
+    /// Pace to a Fixed Rate in Hz Instead of the Monitor's, or None to Stop Overriding.
+    pub fn set_refresh_rate_override(&mut self, refresh_rate: Option<f64>) {
+        self.refresh_rate_override = refresh_rate.filter(|rate| *rate > 0.0);
+    }
+
+    pub fn frame_duration(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.refresh_rate())
+    }
+
+    /// Get the Instant the Next Frame Should Start.
+    pub fn next_frame(&self) -> Instant {
+        self.next_frame
+    }
+
+    /// Returns True if it's Time to Start the Next Frame.
+    pub fn is_frame_due(&self, now: Instant) -> bool {
+        now >= self.next_frame
+    }
+
+    /// Schedule the Frame After the One Starting Now.
+    /// Frames Stay on a Fixed Cadence, but if a Whole Frame was Missed
+    /// the Schedule Restarts from Now Rather than Rushing to Catch Up.
+    pub fn advance(&mut self, now: Instant) -> Instant {
+        let frame_duration = self.frame_duration();
+        let next_frame = self.next_frame + frame_duration;
+
+        self.next_frame = if next_frame > now {
+            next_frame
+        } else {
+            now + frame_duration
+        };
+
+        self.next_frame
+    }
+}
+
+/// Get the Highest Refresh Rate the Window's Current Monitor Supports at its Current Size.
+fn detect_refresh_rate(window: &Window) -> Option<f64> {
+    let monitor = window.current_monitor()?;
+    let size = monitor.size();
+
+    monitor
+        .video_modes()
+        .filter(|mode| mode.size() == size)
+        .map(|mode| mode.refresh_rate())
+        .max()
+        .filter(|rate| *rate > 0)
+        .map(f64::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pacer(detected_refresh_rate: Option<f64>, start: Instant) -> FramePacer {
+        FramePacer {
+            detected_refresh_rate,
+            refresh_rate_override: None,
+            next_frame: start
+        }
+    }
+
+    const FRAME: Duration = Duration::from_millis(20);
+
+    #[test]
+    fn advance_keeps_a_fixed_cadence() {
+        let start = Instant::now();
+        let mut pacer = pacer(Some(50.0), start);
+
+        assert!(pacer.is_frame_due(start));
+
+        // Starting a Frame Late Doesn't Push Back the Ones After It
+        assert_eq!(pacer.advance(start + Duration::from_millis(5)), start + FRAME);
+        assert!(!pacer.is_frame_due(start + Duration::from_millis(19)));
+        assert!(pacer.is_frame_due(start + FRAME));
+
+        assert_eq!(pacer.advance(start + FRAME), start + 2 * FRAME);
+        assert_eq!(pacer.next_frame(), start + 2 * FRAME);
+    }
+
+    #[test]
+    fn advance_restarts_after_a_missed_frame() {
+        let start = Instant::now();
+        let mut pacer = pacer(Some(50.0), start);
+
+        let late = start + Duration::from_millis(45);
+        assert_eq!(pacer.advance(late), late + FRAME);
+    }
+
+    #[test]
+    fn unknown_refresh_rate_uses_default() {
+        assert_eq!(pacer(None, Instant::now()).refresh_rate(), DEFAULT_REFRESH_RATE);
+    }
+
+    #[test]
+    fn override_takes_precedence() {
+        let start = Instant::now();
+        let mut pacer = pacer(Some(144.0), start);
+
+        pacer.set_refresh_rate_override(Some(50.0));
+        assert_eq!(pacer.refresh_rate(), 50.0);
+        assert_eq!(pacer.advance(start), start + FRAME);
+
+        pacer.set_refresh_rate_override(None);
+        assert_eq!(pacer.refresh_rate(), 144.0);
+    }
+
+    #[test]
+    fn override_rejects_non_positive_rates() {
+        let mut pacer = pacer(Some(144.0), Instant::now());
+
+        for rate in &[0.0, -30.0, f64::NAN] {
+            pacer.set_refresh_rate_override(Some(*rate));
+            assert_eq!(pacer.refresh_rate(), 144.0);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+
+use std::time::{Duration, Instant};
+
+/// Weight Given to the Newest Frame Time When Smoothing.
+const SMOOTHING: f64 = 0.1;
+
+/// Measures the Time Between Frames and a Smoothed Frame Rate.
+#[derive(Default)]
+pub struct FrameTimer {
+    last_frame: Option<Instant>,
+
+    // Exponential Moving Average of the Frame Time in Seconds
+    smoothed_frame_time: Option<f64>
+}
+
+impl FrameTimer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a Frame Starting Now and Return the Time Since the Last One.
+    /// The first frame has no previous frame, so its delta is zero.
+    pub fn tick(&mut self, now: Instant) -> Duration {
+        let dt = match self.last_frame {
+            Some(last_frame) => now.saturating_duration_since(last_frame),
+            None => Duration::from_secs(0)
+        };
+
+        self.last_frame = Some(now);
+
+        // Skip Zero Deltas, Which Carry No Rate Information
+        let frame_time = dt.as_secs_f64();
+
+        if frame_time > 0.0 {
+            self.smoothed_frame_time = Some(match self.smoothed_frame_time {
+                Some(smoothed) => smoothed + (frame_time - smoothed) * SMOOTHING,
+                None => frame_time
+            });
+        }
+
+        dt
+    }
+
+    /// Get the Smoothed Time Between Frames, Once Two Frames Have Been Recorded.
+    pub fn frame_time(&self) -> Option<Duration> {
+        self.smoothed_frame_time.map(Duration::from_secs_f64)
+    }
+
+    /// Get the Smoothed Frames per Second, Once Two Frames Have Been Recorded.
+    pub fn fps(&self) -> Option<f64> {
+        self.smoothed_frame_time.map(|frame_time| 1.0 / frame_time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_tick_has_no_rate() {
+        let mut timer = FrameTimer::new();
+
+        assert_eq!(timer.tick(Instant::now()), Duration::from_secs(0));
+        assert_eq!(timer.fps(), None);
+        assert_eq!(timer.frame_time(), None);
+    }
+
+    #[test]
+    fn repeated_instant_does_not_divide_by_zero() {
+        let mut timer = FrameTimer::new();
+        let start = Instant::now();
+        timer.tick(start);
+        timer.tick(start + Duration::from_millis(10));
+
+        // A Zero Delta Leaves the Rate Unchanged
+        assert_eq!(timer.tick(start + Duration::from_millis(10)), Duration::from_secs(0));
+
+        let fps = timer.fps().unwrap();
+        assert!(fps.is_finite(), "{}", fps);
+        assert!((fps - 100.0).abs() < 1e-6, "{}", fps);
+    }
+
+    #[test]
+    fn smoothing_converges_to_steady_frame_time() {
+        let mut timer = FrameTimer::new();
+        let start = Instant::now();
+
+        // Start Slow, then Settle at 60 Frames per Second
+        timer.tick(start);
+        let mut now = start + Duration::from_millis(100);
+        timer.tick(now);
+
+        let frame = Duration::from_micros(16_667);
+        for _ in 0..200 {
+            now += frame;
+            assert_eq!(timer.tick(now), frame);
+        }
+
+        let fps = timer.fps().unwrap();
+        assert!((fps - 60.0).abs() < 0.01, "{}", fps);
+    }
+}
@@ -0,0 +1,113 @@
+
+use gfx_hal::{
+    adapter::{Adapter, PhysicalDevice},
+    device::{Device, OutOfMemory},
+    format::Format,
+    image::{FramebufferAttachment, NumSamples, Usage, ViewCapabilities},
+    window::Extent2D
+};
+
+use super::memory::find_memory_type;
+
+/// An Image, Its Memory, and a View Used as a Render Pass Attachment,
+/// Such as the Depth Buffer or a Multisampled Color Target.
+pub struct AttachmentImage<B: gfx_hal::Backend> {
+    pub image: B::Image,
+    pub memory: B::Memory,
+    pub view: B::ImageView,
+
+    /// Bytes of Memory Backing the Image.
+    pub size: u64,
+
+    pub extent: Extent2D,
+    pub format: Format,
+    pub usage: Usage
+}
+
+impl<B: gfx_hal::Backend> AttachmentImage<B> {
+    /// Create an Attachment Covering the Given Extent.
+    /// Fails if Host or Device Memory Runs Out, Without Leaking What was Created So Far.
+    pub unsafe fn new(
+        device: &B::Device,
+        adapter: &Adapter<B>,
+        format: Format,
+        usage: Usage,
+        extent: Extent2D,
+        samples: NumSamples
+    ) -> Result<Self, OutOfMemory> {
+        use gfx_hal::image::{Kind, Tiling, ViewKind, SubresourceRange, CreationError};
+        use gfx_hal::format::Swizzle;
+        use gfx_hal::memory::Properties;
+        use gfx_hal::device::AllocationError;
+
+        let image = device.create_image(
+            Kind::D2(extent.width, extent.height, 1, samples),
+            1,
+            format,
+            Tiling::Optimal,
+            usage,
+            ViewCapabilities::empty());
+
+        let mut image = match image {
+            Ok(image) => image,
+            Err(CreationError::OutOfMemory(error)) => return Err(error),
+            Err(error) => panic!("Failed to create attachment image: {}", error)
+        };
+
+        // Back the Image with Device Local Memory
+        let requirements = device.get_image_requirements(&image);
+
+        let memory_type = find_memory_type(
+                &adapter.physical_device.memory_properties().memory_types,
+                requirements.type_mask,
+                Properties::DEVICE_LOCAL)
+            .expect("No memory type supports the attachment image");
+
+        let memory = match device.allocate_memory(memory_type, requirements.size) {
+            Ok(memory) => memory,
+            Err(error) => {
+                device.destroy_image(image);
+
+                match error {
+                    AllocationError::OutOfMemory(error) => return Err(error),
+                    AllocationError::TooManyObjects => panic!("Too many memory allocations")
+                }
+            }
+        };
+
+        device
+            .bind_image_memory(&memory, 0, &mut image)
+            .expect("Failed to bind attachment image memory");
+
+        let view = device
+            .create_image_view(
+                &image,
+                ViewKind::D2,
+                format,
+                Swizzle::NO,
+                SubresourceRange {
+                    // Include Stencil for Combined Depth Formats
+                    aspects: format.surface_desc().aspects,
+                    ..SubresourceRange::default()
+                })
+            .expect("Failed to create attachment image view");
+
+        Ok(Self { image, memory, view, size: requirements.size, extent, format, usage })
+    }
+
+    /// Describe the Image to a Framebuffer.
+    pub fn framebuffer_attachment(&self) -> FramebufferAttachment {
+        FramebufferAttachment {
+            usage: self.usage,
+            view_caps: ViewCapabilities::empty(),
+            format: self.format
+        }
+    }
+
+    /// Free the Attachment. The GPU Must Not Be Using It.
+    pub unsafe fn destroy(self, device: &B::Device) {
+        device.destroy_image_view(self.view);
+        device.destroy_image(self.image);
+        device.free_memory(self.memory);
+    }
+}
@@ -0,0 +1,72 @@
+
+use std::mem;
+
+use gfx_hal::{
+    adapter::{Adapter, PhysicalDevice},
+    buffer::Usage,
+    device::Device
+};
+
+use super::memory::find_memory_type;
+
+/// A GPU Buffer Filled Once from the CPU.
+pub struct Buffer<B: gfx_hal::Backend> {
+    pub buffer: B::Buffer,
+    pub memory: B::Memory,
+
+    // Bytes Allocated, Which May Exceed the Data's Size
+    pub size: u64
+}
+
+impl<B: gfx_hal::Backend> Buffer<B> {
+    /// Create a Buffer Holding a Copy of the Data.
+    /// The data must not be empty.
+    pub unsafe fn new<T: Copy>(
+        device: &B::Device,
+        adapter: &Adapter<B>,
+        usage: Usage,
+        data: &[T]
+    ) -> Self {
+        use gfx_hal::memory::{Properties, Segment};
+
+        let data_size = mem::size_of_val(data);
+
+        let mut buffer = device
+            .create_buffer(data_size as u64, usage)
+            .expect("Failed to create buffer");
+
+        // Back the Buffer with Memory the CPU Can Write Without Flushing
+        let requirements = device.get_buffer_requirements(&buffer);
+
+        let memory_type = find_memory_type(
+                &adapter.physical_device.memory_properties().memory_types,
+                requirements.type_mask,
+                Properties::CPU_VISIBLE | Properties::COHERENT)
+            .expect("No memory type supports the buffer");
+
+        let mut memory = device
+            .allocate_memory(memory_type, requirements.size)
+            .expect("Out of memory");
+
+        device
+            .bind_buffer_memory(&memory, 0, &mut buffer)
+            .expect("Failed to bind buffer memory");
+
+        // Copy the Data In
+        let mapping = device
+            .map_memory(&mut memory, Segment::ALL)
+            .expect("Failed to map buffer memory");
+
+        std::ptr::copy_nonoverlapping(data.as_ptr() as *const u8, mapping, data_size);
+
+        device.unmap_memory(&mut memory);
+
+        Self { buffer, memory, size: requirements.size }
+    }
+
+    /// Free the Buffer. The GPU Must Not Be Using It.
+    pub unsafe fn destroy(self, device: &B::Device) {
+        device.destroy_buffer(self.buffer);
+        device.free_memory(self.memory);
+    }
+}
@@ -0,0 +1,115 @@
+
+use gfx_hal::format::{ChannelType, Format};
+
+/// How the RGB Channels of a Color are Encoded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorSpace {
+    /// Proportional to Light Intensity, as Shaders Compute Lighting.
+    Linear,
+
+    /// Gamma Encoded, as Colors from Color Pickers and Image Editors are.
+    Srgb
+}
+
+/// Get the Clear Value that Makes an Attachment of the Given Format Display the Color.
+///
+/// SRGB attachments encode clear values on write, so they need linear values.
+/// Other formats store them unchanged, so they need values already encoded for display.
+/// Alpha is always linear and passes through.
+pub fn clear_value(color: [f32; 4], space: ColorSpace, format: Format) -> [f32; 4] {
+    let is_srgb_target = format.base_format().1 == ChannelType::Srgb;
+
+    let convert: fn(f32) -> f32 = match (space, is_srgb_target) {
+        (ColorSpace::Srgb, true) => srgb_to_linear,
+        (ColorSpace::Linear, false) => linear_to_srgb,
+        _ => return color
+    };
+
+    [convert(color[0]), convert(color[1]), convert(color[2]), color[3]]
+}
+
+/// Decode One SRGB Channel to Linear.
+pub fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encode One Linear Channel as SRGB.
+pub fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 1e-5;
+
+    fn assert_close(actual: [f32; 4], expected: [f32; 4]) {
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            assert!((a - e).abs() < EPSILON, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    // Mid Gray in Each Encoding
+    const LINEAR_GRAY: f32 = 0.214_041_14;
+    const SRGB_GRAY: f32 = 0.5;
+
+    #[test]
+    fn srgb_color_on_srgb_target_is_decoded() {
+        let value = clear_value([SRGB_GRAY; 4], ColorSpace::Srgb, Format::Bgra8Srgb);
+        assert_close(value, [LINEAR_GRAY, LINEAR_GRAY, LINEAR_GRAY, SRGB_GRAY]);
+    }
+
+    #[test]
+    fn srgb_color_on_unorm_target_is_unchanged() {
+        let value = clear_value([SRGB_GRAY; 4], ColorSpace::Srgb, Format::Bgra8Unorm);
+        assert_eq!(value, [SRGB_GRAY; 4]);
+    }
+
+    #[test]
+    fn linear_color_on_srgb_target_is_unchanged() {
+        let value = clear_value([LINEAR_GRAY; 4], ColorSpace::Linear, Format::Rgba8Srgb);
+        assert_eq!(value, [LINEAR_GRAY; 4]);
+    }
+
+    #[test]
+    fn linear_color_on_unorm_target_is_encoded() {
+        let value = clear_value([LINEAR_GRAY; 4], ColorSpace::Linear, Format::Rgba8Unorm);
+        assert_close(value, [SRGB_GRAY, SRGB_GRAY, SRGB_GRAY, LINEAR_GRAY]);
+    }
+
+    #[test]
+    fn alpha_passes_through() {
+        for space in &[ColorSpace::Linear, ColorSpace::Srgb] {
+            for format in &[Format::Bgra8Srgb, Format::Bgra8Unorm] {
+                assert_eq!(clear_value([0.3, 0.6, 0.9, 0.25], *space, *format)[3], 0.25);
+            }
+        }
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        for step in 0..=100 {
+            let value = step as f32 / 100.0;
+
+            assert!((linear_to_srgb(srgb_to_linear(value)) - value).abs() < EPSILON, "{}", value);
+            assert!((srgb_to_linear(linear_to_srgb(value)) - value).abs() < EPSILON, "{}", value);
+        }
+    }
+
+    #[test]
+    fn conversions_keep_black_and_white() {
+        assert_eq!(srgb_to_linear(0.0), 0.0);
+        assert_eq!(linear_to_srgb(0.0), 0.0);
+        assert!((srgb_to_linear(1.0) - 1.0).abs() < EPSILON);
+        assert!((linear_to_srgb(1.0) - 1.0).abs() < EPSILON);
+    }
+}
@@ -1,19 +1,265 @@
 
-use shaderc::ShaderKind;
+use std::{fs, path::{Path, PathBuf}};
+
+use shaderc::{ShaderKind, IncludeType, ResolvedInclude};
+
+/// GLSL Source Code and the File it Came From.
+#[derive(Debug, Clone, Copy)]
+pub struct ShaderSource<'a> {
+    /// Path Shown in Compiler Diagnostics, and the Base for Relative `#include`s.
+    pub file_name: &'a str,
+    pub text: &'a str
+}
 
 /// Compiles GLSL Source Code into a SPIR-V Binary.
-pub fn compile_shader(source_text: &str, shader_kind: ShaderKind) -> Vec<u32> {
+/// On Failure the Error Holds the Compiler's Diagnostics, Including Line Numbers.
+pub fn compile_shader(
+    source_text: &str,
+    shader_kind: ShaderKind,
+    file_name: &str
+) -> Result<Vec<u32>, shaderc::Error> {
+    compile_shader_variant(source_text, shader_kind, file_name, &[], &[])
+}
+
+/// Compiles GLSL Source Code that `#include`s Files Under a Root Directory.
+/// A missing file fails compilation with an error naming the include.
+pub fn compile_shader_with_includes(
+    source_text: &str,
+    shader_kind: ShaderKind,
+    file_name: &str,
+    include_root: &Path
+) -> Result<Vec<u32>, shaderc::Error> {
+    compile_shader_variant(source_text, shader_kind, file_name, &[], &[include_root.to_path_buf()])
+}
+
+/// Compiles a Variant of GLSL Source Code into a SPIR-V Binary.
+/// Each Define Acts Like `#define NAME VALUE` at the Top of the Source,
+/// so One Source Can Be Specialized with `#ifdef` Blocks.
+///
+/// `#include` Directives are Resolved Against `include_paths` in Order.
+pub fn compile_shader_variant(
+    source_text: &str,
+    shader_kind: ShaderKind,
+    file_name: &str,
+    defines: &[(&str, Option<&str>)],
+    include_paths: &[PathBuf]
+) -> Result<Vec<u32>, shaderc::Error> {
     let mut compiler = shaderc::Compiler::new().unwrap();
 
-    let input_file = "unnamed"; // used in error messages
     let entry_point = "main";
-    let options = None;
 
-    compiler
+    let mut options = shaderc::CompileOptions::new().unwrap();
+    for (name, value) in defines {
+        options.add_macro_definition(name, *value);
+    }
+
+    options.set_include_callback(|requested, include_type, requesting_source, _depth| {
+        resolve_include(requested, include_type, requesting_source, include_paths)
+    });
+
+    let artifact = compiler
         .compile_into_spirv(
         	source_text, shader_kind,
-        	input_file, entry_point, options)
-        .expect("Failed to compile shader")
-        .as_binary()
-        .to_vec()
+        	file_name, entry_point, Some(&options))?;
+
+    // Warnings Don't Stop Compilation
+    if artifact.get_num_warnings() > 0 {
+        eprintln!("Warning: {}", artifact.get_warning_messages());
+    }
+
+    Ok(artifact.as_binary().to_vec())
+}
+
+/// Find the File an `#include` Refers to and Read It.
+///
+/// `#include "file"` is tried next to the including file first,
+/// then in each search path. `#include <file>` only uses the search paths.
+fn resolve_include(
+    requested: &str,
+    include_type: IncludeType,
+    requesting_source: &str,
+    include_paths: &[PathBuf]
+) -> Result<ResolvedInclude, String> {
+    // A Bare File Name Has an Empty Parent, Which Would Search the Working Directory
+    let including_directory = Path::new(requesting_source)
+        .parent()
+        .filter(|directory| !directory.as_os_str().is_empty())
+        .filter(|_| include_type == IncludeType::Relative);
+
+    let directories: Vec<&Path> = including_directory
+        .into_iter()
+        .chain(include_paths.iter().map(PathBuf::as_path))
+        .collect();
+
+    for directory in &directories {
+        let candidate = directory.join(requested);
+
+        if let Ok(content) = fs::read_to_string(&candidate) {
+            return Ok(ResolvedInclude {
+                resolved_name: candidate.to_string_lossy().into_owned(),
+                content
+            });
+        }
+    }
+
+    Err(format!(
+        "could not resolve include \"{}\" from {}; searched {:?}",
+        requested, requesting_source, directories))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A Directory of Include Files, Removed When Dropped.
+    struct IncludeRoot(PathBuf);
+
+    impl IncludeRoot {
+        fn new(name: &str, files: &[(&str, &str)]) -> Self {
+            let path = std::env::temp_dir()
+                .join(format!("rust_engine_{}_{}", name, std::process::id()));
+            fs::create_dir_all(&path).unwrap();
+
+            for (file_name, text) in files {
+                fs::write(path.join(file_name), text).unwrap();
+            }
+
+            IncludeRoot(path)
+        }
+    }
+
+    impl Drop for IncludeRoot {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn relative_include_from_bare_name_skips_working_directory() {
+        // Tests Run from the Crate Root, Which Holds Cargo.toml
+        let error = resolve_include("Cargo.toml", IncludeType::Relative, "shader.vert", &[])
+            .unwrap_err();
+
+        assert!(error.contains("searched []"), "{}", error);
+    }
+
+    #[test]
+    fn relative_include_from_bare_name_uses_search_paths() {
+        let root = IncludeRoot::new("bare_name_include", &[("common.glsl", "// Common")]);
+
+        let resolved = resolve_include(
+                "common.glsl", IncludeType::Relative, "shader.vert", std::slice::from_ref(&root.0))
+            .unwrap();
+
+        assert_eq!(resolved.resolved_name, root.0.join("common.glsl").to_string_lossy());
+        assert_eq!(resolved.content, "// Common");
+    }
+
+    #[test]
+    fn relative_include_prefers_including_directory() {
+        let including = IncludeRoot::new("including_directory", &[("common.glsl", "// Local")]);
+        let root = IncludeRoot::new("including_directory_root", &[("common.glsl", "// Root")]);
+
+        let requesting = including.0.join("shader.vert");
+        let resolved = resolve_include(
+                "common.glsl", IncludeType::Relative, &requesting.to_string_lossy(), std::slice::from_ref(&root.0))
+            .unwrap();
+
+        assert_eq!(resolved.content, "// Local");
+    }
+
+    const FRAGMENT_WITH_INCLUDE: &str = "
+        #version 450
+        #include <tint.glsl>
+        layout(location = 0) out vec4 fragment_color;
+        void main() { fragment_color = vec4(tint(), 1.0); }
+    ";
+
+    #[test]
+    fn resolves_include_from_root() {
+        let root = IncludeRoot::new("resolves_include", &[
+            ("tint.glsl", "vec3 tint() { return vec3(1.0, 0.5, 0.0); }")
+        ]);
+
+        let binary = compile_shader_with_includes(
+                FRAGMENT_WITH_INCLUDE, ShaderKind::Fragment, "tinted.frag", &root.0)
+            .unwrap();
+
+        // Every SPIR-V Module Starts with the Magic Number
+        assert_eq!(binary[0], 0x0723_0203);
+    }
+
+    #[test]
+    fn missing_include_names_file_and_searched_paths() {
+        let root = IncludeRoot::new("missing_include", &[]);
+
+        let error = compile_shader_with_includes(
+                FRAGMENT_WITH_INCLUDE, ShaderKind::Fragment, "tinted.frag", &root.0)
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.contains("\"tint.glsl\""), "{}", error);
+        assert!(error.contains(&format!("{:?}", root.0)), "{}", error);
+    }
+
+    #[test]
+    fn quoted_include_from_bare_name_resolves_from_root() {
+        let root = IncludeRoot::new("quoted_include", &[
+            ("tint.glsl", "vec3 tint() { return vec3(1.0, 0.5, 0.0); }")
+        ]);
+        let source = FRAGMENT_WITH_INCLUDE.replace("<tint.glsl>", "\"tint.glsl\"");
+
+        let binary = compile_shader_with_includes(
+                &source, ShaderKind::Fragment, "tinted.frag", &root.0)
+            .unwrap();
+
+        assert_eq!(binary[0], 0x0723_0203);
+    }
+
+    #[test]
+    fn quoted_include_from_bare_name_only_searches_root() {
+        let root = IncludeRoot::new("quoted_missing_include", &[]);
+        let source = FRAGMENT_WITH_INCLUDE.replace("<tint.glsl>", "\"Cargo.toml\"");
+
+        // Cargo.toml is in the Working Directory but Must Not Be Found There
+        let error = compile_shader_with_includes(
+                &source, ShaderKind::Fragment, "tinted.frag", &root.0)
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.contains(&format!("searched [{:?}]", root.0)), "{}", error);
+    }
+
+    const FRAGMENT_WITH_VARIANTS: &str = "
+        #version 450
+        layout(location = 0) out vec4 fragment_color;
+        void main() {
+        #ifdef RED
+            fragment_color = vec4(1.0, 0.0, 0.0, BRIGHTNESS);
+        #else
+            fragment_color = vec4(0.0, 0.0, 1.0, BRIGHTNESS);
+        #endif
+        }
+    ";
+
+    #[test]
+    fn defines_select_variant() {
+        let compile = |defines: &[(&str, Option<&str>)]| compile_shader_variant(
+                FRAGMENT_WITH_VARIANTS, ShaderKind::Fragment, "variants.frag", defines, &[])
+            .unwrap();
+
+        let red = compile(&[("RED", None), ("BRIGHTNESS", Some("1.0"))]);
+        let blue = compile(&[("BRIGHTNESS", Some("1.0"))]);
+
+        assert_ne!(red, blue);
+    }
+
+    #[test]
+    fn variant_fails_without_required_define() {
+        let result = compile_shader_variant(
+            FRAGMENT_WITH_VARIANTS, ShaderKind::Fragment, "variants.frag", &[("RED", None)], &[]);
+
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("BRIGHTNESS"), "{}", error);
+    }
 }
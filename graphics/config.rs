@@ -0,0 +1,203 @@
+
+use std::path::PathBuf;
+
+use gfx_hal::{
+    Features,
+    format::Format,
+    pso::{BlendState, BlendOp, Face, FrontFace},
+    window::{CompositeAlphaMode, PresentMode}
+};
+
+use super::color::ColorSpace;
+
+/// How Fragment Colors Combine with the Color Already in the Target.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlendMode {
+    /// Overwrite the Target. Correct for opaque geometry in any draw order.
+    Opaque,
+
+    /// Blend by Source Alpha. Results depend on draw order.
+    Alpha,
+
+    /// Blend Colors Already Multiplied by Their Alpha.
+    /// Avoids dark fringes when compositing.
+    PremultipliedAlpha,
+
+    /// Blend Color and Alpha Channels with Independent Operations.
+    Separate {
+        color: BlendOp,
+        alpha: BlendOp
+    }
+}
+
+impl BlendMode {
+    /// Get the Pipeline Blend State, or None to Disable Blending.
+    pub fn blend_state(self) -> Option<BlendState> {
+        match self {
+            BlendMode::Opaque => None,
+            BlendMode::Alpha => Some(BlendState::ALPHA),
+            BlendMode::PremultipliedAlpha => Some(BlendState::PREMULTIPLIED_ALPHA),
+            BlendMode::Separate { color, alpha } => Some(BlendState { color, alpha })
+        }
+    }
+}
+
+/// Optional Settings Used When Creating a Renderer.
+#[derive(Debug, Clone)]
+pub struct RendererConfig {
+    /// Color Format to Use for the Swapchain and Render Pass.
+    ///
+    /// The color format is chosen in this order:
+    /// 1. `preferred_color_format`, if the surface supports it.
+    /// 2. The first SRGB format the surface supports.
+    /// 3. The first format the surface supports.
+    /// 4. `Rgba8Srgb`, if the surface reports no formats.
+    ///
+    /// An unsupported preferred format prints a warning and falls
+    /// through to automatic selection. The pipeline always targets
+    /// whichever format was chosen.
+    pub preferred_color_format: Option<Format>,
+
+    /// Device Features to Enable.
+    /// The renderer needs none itself, so the device is opened with exactly these.
+    /// Creation fails if the adapter doesn't support all of them.
+    pub features: Features,
+
+    /// RGBA Color the Screen is Cleared to Each Frame.
+    pub clear_color: [f32; 4],
+
+    /// Encoding of `clear_color`, Converted to Match the Color Format.
+    pub clear_color_space: ColorSpace,
+
+    /// Value the Depth Buffer is Cleared to Each Frame.
+    pub clear_depth: f32,
+
+    /// Number of Consecutive Frames the Surface May Fail to Acquire or Present
+    /// Before `render` Gives Up with `RenderError::SurfaceLost`.
+    pub surface_retry_budget: u32,
+
+    /// Number of Consecutive Frames the GPU May Take Over a Second to Finish
+    /// Before `render` Gives Up with `RenderError::FrameTimeout`.
+    /// Each such frame is skipped rather than drawn.
+    pub fence_timeout_budget: u32,
+
+    /// File to Load the Pipeline Cache from and Save it to on Drop.
+    /// Speeds up pipeline creation on later runs. `None` disables persistence.
+    pub pipeline_cache_path: Option<PathBuf>,
+
+    /// How the Pipeline Blends Fragments into the Color Target.
+    pub blend_mode: BlendMode,
+
+    /// Priority of Each Queue to Open, from 0.0 to 1.0.
+    /// The length is the queue count, which must be between 1 and the
+    /// queue family's maximum. Rendering uses the first queue.
+    /// Creation fails with `CreationError::InvalidQueuePriority` for NaN or out of range values.
+    pub queue_priorities: Vec<f32>,
+
+    /// Preprocessor Macros Defined When Compiling Both Shaders.
+    /// Selects a variant of shaders written with `#ifdef` blocks.
+    pub shader_defines: Vec<(String, Option<String>)>,
+
+    /// Directories Searched in Order to Resolve Shader `#include` Directives,
+    /// e.g. engine built-in shaders followed by project shaders.
+    pub shader_include_paths: Vec<PathBuf>,
+
+    /// Wait for Vertical Blank When Presenting.
+    /// Without vsync, Mailbox is preferred over Immediate, and FIFO is
+    /// used with a warning if the surface supports neither.
+    pub vsync: bool,
+
+    /// Present Mode to Use Instead of the One `vsync` Selects.
+    /// An unsupported mode falls back to FIFO.
+    pub present_mode: Option<PresentMode>,
+
+    /// Which Faces the Rasterizer Discards.
+    pub cull_face: Face,
+
+    /// Vertex Winding that Makes a Triangle Front Facing.
+    /// Meshes exported with the opposite winding are invisible under back-face culling.
+    pub front_face: FrontFace,
+
+    /// How the Surface's Alpha Blends with the Windows Behind It.
+    /// `None` inherits the platform's mode where possible. An unsupported mode
+    /// prints a warning and falls back to opaque, or to any supported mode if opaque isn't.
+    /// Transparent windows also need `WindowConfig::transparent` and a clear color
+    /// with alpha below 1.0.
+    pub composite_alpha_mode: Option<CompositeAlphaMode>,
+
+    /// Samples per Pixel for Multisample Anti-Aliasing, e.g. 4. 1 disables it.
+    /// A count the color and depth formats don't both support prints a warning
+    /// and falls back to 1. If the buffers don't fit in memory when the window is
+    /// resized, the count is halved until they do, with a warning.
+    pub samples: u8,
+
+    /// Record Draws Once into a Secondary Command Buffer and Replay it Each Frame.
+    /// The recording is redone only when the viewport, scissor or model matrix changes.
+    pub static_commands: bool
+}
+
+impl Default for RendererConfig {
+    fn default() -> Self {
+        Self {
+            preferred_color_format: None,
+            features: Features::empty(),
+            clear_color: [0.0, 0.0, 0.0, 1.0],
+            clear_color_space: ColorSpace::Linear,
+            clear_depth: 1.0,
+            surface_retry_budget: 60,
+            fence_timeout_budget: 5,
+            pipeline_cache_path: None,
+            blend_mode: BlendMode::Opaque,
+            queue_priorities: vec![1.0],
+            shader_defines: Vec::new(),
+            shader_include_paths: Vec::new(),
+            vsync: true,
+            present_mode: None,
+            cull_face: Face::BACK,
+            front_face: FrontFace::CounterClockwise,
+            composite_alpha_mode: None,
+            samples: 1,
+            static_commands: false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gfx_hal::pso::Factor;
+
+    use super::*;
+
+    #[test]
+    fn opaque_disables_blending_by_default() {
+        assert_eq!(RendererConfig::default().blend_mode, BlendMode::Opaque);
+        assert_eq!(BlendMode::Opaque.blend_state(), None);
+    }
+
+    #[test]
+    fn alpha_blends_by_source_alpha() {
+        let state = BlendMode::Alpha.blend_state().unwrap();
+
+        assert_eq!(state.color, BlendOp::Add { src: Factor::SrcAlpha, dst: Factor::OneMinusSrcAlpha });
+    }
+
+    #[test]
+    fn premultiplied_alpha_adds_source_unscaled() {
+        let state = BlendMode::PremultipliedAlpha.blend_state().unwrap();
+        let premultiplied = BlendOp::Add { src: Factor::One, dst: Factor::OneMinusSrcAlpha };
+
+        assert_eq!(state.color, premultiplied);
+        assert_eq!(state.alpha, premultiplied);
+    }
+
+    #[test]
+    fn separate_keeps_color_and_alpha_independent() {
+        let color = BlendOp::Add { src: Factor::SrcAlpha, dst: Factor::OneMinusSrcAlpha };
+        let alpha = BlendOp::Max;
+
+        let state = BlendMode::Separate { color, alpha }.blend_state().unwrap();
+
+        assert_eq!(state.color, color);
+        assert_eq!(state.alpha, alpha);
+    }
+}
@@ -0,0 +1,64 @@
+
+use gfx_hal::{
+    adapter::PhysicalDevice,
+    format::Format
+};
+
+/// Depth Formats in Order of Preference.
+/// Higher precision first, then formats with stencil, then the 16 bit fallback.
+const PREFERRED_FORMATS: [Format; 5] = [
+    Format::D32Sfloat,
+    Format::D24UnormS8Uint,
+    Format::D32SfloatS8Uint,
+    Format::X8D24Unorm,
+    Format::D16Unorm
+];
+
+/// Pick the Most Preferred Depth Format the Adapter Can Render to.
+pub fn select_format<B: gfx_hal::Backend>(physical_device: &B::PhysicalDevice) -> Option<Format> {
+    use gfx_hal::format::ImageFeature;
+
+    select_supported(|format| {
+        physical_device
+            .format_properties(Some(format))
+            .optimal_tiling
+            .contains(ImageFeature::DEPTH_STENCIL_ATTACHMENT)
+    })
+}
+
+/// Pick the First Preferred Format `is_supported` Accepts.
+fn select_supported(is_supported: impl Fn(Format) -> bool) -> Option<Format> {
+    PREFERRED_FORMATS
+        .iter()
+        .copied()
+        .find(|format| is_supported(*format))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_supported_preference_is_selected() {
+        let supported = [Format::D16Unorm, Format::D24UnormS8Uint, Format::X8D24Unorm];
+
+        assert_eq!(
+            select_supported(|format| supported.contains(&format)),
+            Some(Format::D24UnormS8Uint));
+    }
+
+    #[test]
+    fn selection_is_among_supported_formats() {
+        for supported in &PREFERRED_FORMATS {
+            assert_eq!(select_supported(|format| format == *supported), Some(*supported));
+        }
+
+        assert_eq!(select_supported(|_| true), Some(PREFERRED_FORMATS[0]));
+    }
+
+    #[test]
+    fn nothing_supported_selects_none() {
+        assert_eq!(select_supported(|_| false), None);
+        assert_eq!(select_supported(|format| format == Format::Rgba8Srgb), None);
+    }
+}
@@ -0,0 +1,133 @@
+
+use std::fmt;
+
+/// An Error Raised While Creating a Renderer.
+#[derive(Debug)]
+pub enum CreationError {
+    /// The Adapter Doesn't Support Some Requested Features.
+    MissingFeatures(gfx_hal::Features),
+
+    /// The Adapter Can't Render to Any of the Preferred Depth Formats.
+    NoDepthFormat,
+
+    /// The Queue Family Can't Provide the Requested Number of Queues.
+    InvalidQueueCount {
+        requested: usize,
+        max: usize
+    },
+
+    /// A Queue Priority is NaN or Outside 0.0 to 1.0.
+    InvalidQueuePriority {
+        index: usize,
+        priority: f32
+    },
+
+    /// A Shader Failed to Compile. Holds the Compiler's Diagnostics.
+    ShaderCompilation(shaderc::Error),
+
+    /// The Mesh Can't Be Drawn.
+    InvalidMesh(InvalidMeshReason),
+
+    /// The Adapter Allows Fewer Push Constant Bytes than the Renderer Needs.
+    PushConstantsTooLarge {
+        required: usize,
+        max: usize
+    }
+}
+
+impl fmt::Display for CreationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CreationError::MissingFeatures(missing) =>
+                write!(f, "adapter does not support requested features: {:?}", missing),
+            CreationError::NoDepthFormat =>
+                write!(f, "adapter does not support any depth attachment format"),
+            CreationError::InvalidQueueCount { requested, max } =>
+                write!(f, "requested {} queues but the queue family allows 1 to {}", requested, max),
+            CreationError::InvalidQueuePriority { index, priority } =>
+                write!(f, "queue {} has priority {} but priorities must be from 0.0 to 1.0", index, priority),
+            CreationError::ShaderCompilation(error) =>
+                write!(f, "failed to compile shader: {}", error),
+            CreationError::InvalidMesh(reason) =>
+                write!(f, "invalid mesh: {}", reason),
+            CreationError::PushConstantsTooLarge { required, max } =>
+                write!(f, "renderer needs {} bytes of push constants but the adapter allows {}", required, max)
+        }
+    }
+}
+
+impl std::error::Error for CreationError {}
+
+/// An Error Raised While Rendering a Frame.
+#[derive(Debug)]
+pub enum RenderError {
+    /// The Surface Failed for Too Many Consecutive Frames to Keep Retrying.
+    SurfaceLost {
+        failed_frames: u32
+    },
+
+    /// The GPU Took Too Long to Finish Frames Too Many Times in a Row.
+    FrameTimeout {
+        timed_out_frames: u32
+    },
+
+    /// The Device Stopped Responding. The Renderer Can't Recover and Must Be Recreated.
+    DeviceLost(DeviceLostReason)
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RenderError::SurfaceLost { failed_frames } =>
+                write!(f, "surface lost after {} consecutive failed frames", failed_frames),
+            RenderError::FrameTimeout { timed_out_frames } =>
+                write!(f, "GPU took over a second to finish {} consecutive frames", timed_out_frames),
+            RenderError::DeviceLost(reason) =>
+                write!(f, "device lost while {}", reason)
+        }
+    }
+}
+
+impl std::error::Error for RenderError {}
+
+/// The Operation that Found the Device Lost.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeviceLostReason {
+    FenceWait,
+    AcquireImage,
+    Present
+}
+
+impl fmt::Display for DeviceLostReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DeviceLostReason::FenceWait => write!(f, "waiting for the previous frame"),
+            DeviceLostReason::AcquireImage => write!(f, "acquiring a swapchain image"),
+            DeviceLostReason::Present => write!(f, "presenting")
+        }
+    }
+}
+
+/// What Makes a Mesh Impossible to Draw.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InvalidMeshReason {
+    /// There are No Vertices or No Indices.
+    Empty,
+
+    /// An Index Refers Past the Last Vertex.
+    IndexOutOfRange {
+        index: u16,
+        vertex_count: usize
+    }
+}
+
+impl fmt::Display for InvalidMeshReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InvalidMeshReason::Empty =>
+                write!(f, "a mesh needs at least one vertex and index"),
+            InvalidMeshReason::IndexOutOfRange { index, vertex_count } =>
+                write!(f, "index {} is out of range of {} vertices", index, vertex_count)
+        }
+    }
+}
@@ -0,0 +1,70 @@
+
+use gfx_hal::{
+    MemoryTypeId,
+    adapter::{MemoryType, MemoryHeap},
+    memory::{Properties, HeapFlags}
+};
+
+/// A Best-Effort Summary of GPU Memory.
+///
+/// gfx-hal reports heap sizes on every backend but has no budget or
+/// usage query, so `budget` is always `None` and usage only counts
+/// memory the renderer allocated itself.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryReport {
+    pub heaps: Vec<HeapReport>,
+
+    /// Bytes Allocated by the Renderer for its Own Resources.
+    pub renderer_allocated: u64,
+
+    /// Bytes the Driver Lets this Process Use, if Known.
+    pub budget: Option<u64>
+}
+
+/// The Size of One Memory Heap.
+#[derive(Debug, Clone, Copy)]
+pub struct HeapReport {
+    pub size: u64,
+    pub device_local: bool
+}
+
+impl MemoryReport {
+    pub fn new(heaps: &[MemoryHeap], renderer_allocated: u64) -> Self {
+        Self {
+            heaps: heaps
+                .iter()
+                .map(|heap| HeapReport {
+                    size: heap.size,
+                    device_local: heap.flags.contains(HeapFlags::DEVICE_LOCAL)
+                })
+                .collect(),
+            renderer_allocated,
+            budget: None
+        }
+    }
+
+    /// Get the Total Size of Device Local Heaps, Roughly the GPU's VRAM.
+    pub fn device_local_size(&self) -> u64 {
+        self.heaps
+            .iter()
+            .filter(|heap| heap.device_local)
+            .map(|heap| heap.size)
+            .sum()
+    }
+}
+
+/// Find a Memory Type Allowed by a Resource's Type Mask with the Given Properties.
+pub fn find_memory_type(
+    memory_types: &[MemoryType],
+    type_mask: u32,
+    properties: Properties
+) -> Option<MemoryTypeId> {
+    memory_types
+        .iter()
+        .enumerate()
+        .position(|(id, memory_type)| {
+            type_mask & (1 << id) != 0
+            && memory_type.properties.contains(properties)
+        })
+        .map(MemoryTypeId)
+}
@@ -0,0 +1,88 @@
+
+use gfx_hal::{
+    adapter::Adapter,
+    buffer::Usage
+};
+
+use super::buffer::Buffer;
+use super::vertex::Vertex;
+use super::error::InvalidMeshReason;
+
+/// Check the Indices Form a Drawable Mesh Over the Vertices.
+pub fn validate(vertices: &[Vertex], indices: &[u16]) -> Result<(), InvalidMeshReason> {
+    if vertices.is_empty() || indices.is_empty() {
+        return Err(InvalidMeshReason::Empty);
+    }
+
+    match indices.iter().find(|index| **index as usize >= vertices.len()) {
+        Some(index) => Err(InvalidMeshReason::IndexOutOfRange {
+            index: *index,
+            vertex_count: vertices.len()
+        }),
+        None => Ok(())
+    }
+}
+
+/// Indexed Triangles Uploaded to the GPU.
+pub struct Mesh<B: gfx_hal::Backend> {
+    pub vertex_buffer: Buffer<B>,
+    pub index_buffer: Buffer<B>,
+    pub index_count: u32
+}
+
+impl<B: gfx_hal::Backend> Mesh<B> {
+    /// Upload Vertices and the 16 Bit Indices of a Triangle List.
+    /// The mesh must pass `validate`.
+    pub unsafe fn new(
+        device: &B::Device,
+        adapter: &Adapter<B>,
+        vertices: &[Vertex],
+        indices: &[u16]
+    ) -> Self {
+        Self {
+            vertex_buffer: Buffer::new(device, adapter, Usage::VERTEX, vertices),
+            index_buffer: Buffer::new(device, adapter, Usage::INDEX, indices),
+            index_count: indices.len() as u32
+        }
+    }
+
+    /// Get the Bytes Allocated for Both Buffers.
+    pub fn size(&self) -> u64 {
+        self.vertex_buffer.size + self.index_buffer.size
+    }
+
+    /// Free the Mesh. The GPU Must Not Be Using It.
+    pub unsafe fn destroy(self, device: &B::Device) {
+        self.vertex_buffer.destroy(device);
+        self.index_buffer.destroy(device);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRIANGLE: [Vertex; 3] = [
+        Vertex { position: [0.0, -0.5, 0.0], color: [1.0, 0.0, 0.0] },
+        Vertex { position: [-0.5, 0.5, 0.0], color: [0.0, 1.0, 0.0] },
+        Vertex { position: [0.5, 0.5, 0.0], color: [0.0, 0.0, 1.0] }
+    ];
+
+    #[test]
+    fn indices_within_vertices_are_valid() {
+        assert_eq!(validate(&TRIANGLE, &[0, 1, 2]), Ok(()));
+    }
+
+    #[test]
+    fn empty_geometry_is_invalid() {
+        assert_eq!(validate(&[], &[0, 1, 2]), Err(InvalidMeshReason::Empty));
+        assert_eq!(validate(&TRIANGLE, &[]), Err(InvalidMeshReason::Empty));
+    }
+
+    #[test]
+    fn out_of_range_index_is_invalid() {
+        assert_eq!(
+            validate(&TRIANGLE, &[0, 1, 3]),
+            Err(InvalidMeshReason::IndexOutOfRange { index: 3, vertex_count: 3 }));
+    }
+}
@@ -2,4 +2,23 @@
 mod renderer;
 pub use renderer::Renderer;
 
+mod vertex;
+pub use vertex::Vertex;
+
+pub mod config;
+pub use config::RendererConfig;
+
+pub mod color;
+pub mod error;
+pub mod memory;
+pub mod render_thread;
+
 mod compile_shader;
+pub use compile_shader::{compile_shader, compile_shader_with_includes, ShaderSource};
+mod scissor;
+mod retry_budget;
+mod depth;
+mod attachment;
+mod buffer;
+mod mesh;
+mod pipeline_cache;
@@ -0,0 +1,50 @@
+
+use std::{fs, path::Path};
+
+use gfx_hal::{
+    adapter::PhysicalDevice,
+    device::Device
+};
+
+/// Create a Pipeline Cache Seeded from a File Written by a Previous Run.
+/// A Missing, Corrupt, or Incompatible File is Ignored and the Cache Starts Empty.
+pub unsafe fn load<B: gfx_hal::Backend>(
+    device: &B::Device,
+    physical_device: &B::PhysicalDevice,
+    path: Option<&Path>
+) -> B::PipelineCache {
+    let data = path
+        .and_then(|path| fs::read(path).ok())
+        .filter(|data| {
+            let is_valid = physical_device.is_valid_cache(data);
+
+            if !is_valid {
+                eprintln!("Warning: discarding incompatible pipeline cache");
+            }
+
+            is_valid
+        });
+
+    // Fall Back to an Empty Cache if the Driver Rejects the Data
+    device
+        .create_pipeline_cache(data.as_deref())
+        .or_else(|_| device.create_pipeline_cache(None))
+        .expect("Out of memory")
+}
+
+/// Write a Pipeline Cache to a File so the Next Run Can Reuse It.
+/// Failing to Save Only Prints a Warning.
+pub unsafe fn save<B: gfx_hal::Backend>(
+    device: &B::Device,
+    cache: &B::PipelineCache,
+    path: &Path
+) {
+    let result = device
+        .get_pipeline_cache_data(cache)
+        .map_err(|error| error.to_string())
+        .and_then(|data| fs::write(path, data).map_err(|error| error.to_string()));
+
+    if let Err(error) = result {
+        eprintln!("Warning: failed to save pipeline cache to {:?}: {}", path, error);
+    }
+}
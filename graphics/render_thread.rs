@@ -0,0 +1,251 @@
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+        mpsc::{self, Sender, Receiver}
+    },
+    thread::{self, JoinHandle}
+};
+
+use gfx_hal::pso::Rect;
+
+use super::renderer::{Renderer, Matrix4};
+use super::color::ColorSpace;
+use super::error::RenderError;
+
+/// A Request Sent from the Event Loop to the Render Thread.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RenderCommand {
+    Resize([u32; 2]),
+    RecreateSwapchain,
+    PushScissor(Rect),
+    PopScissor,
+    SetClearColor([f32; 4], ColorSpace),
+    SetClearDepth(f32),
+    SetModelMatrix(Matrix4),
+
+    /// Draw and Present a Frame, Skipped While the Surface Has No Area.
+    Render
+}
+
+/// Something the Render Thread Can Drive with Commands.
+pub trait CommandTarget {
+    /// Carry Out a Command. Returns Whether a Frame was Rendered.
+    fn execute(&mut self, command: RenderCommand) -> Result<bool, RenderError>;
+}
+
+impl<B: gfx_hal::Backend> CommandTarget for Renderer<B> {
+    fn execute(&mut self, command: RenderCommand) -> Result<bool, RenderError> {
+        match command {
+            RenderCommand::Resize(size) => self.update_dimensions(size),
+            RenderCommand::RecreateSwapchain => self.recreate_swapchain(),
+            RenderCommand::PushScissor(rect) => self.push_scissor(rect),
+            RenderCommand::PopScissor => { self.pop_scissor(); },
+            RenderCommand::SetClearColor(color, space) => self.set_clear_color(color, space),
+            RenderCommand::SetClearDepth(depth) => self.set_clear_depth(depth),
+            RenderCommand::SetModelMatrix(matrix) => self.set_model_matrix(matrix),
+
+            RenderCommand::Render => {
+                self.configure_swapchain()?;
+
+                if self.is_renderable() {
+                    self.render()?;
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+// Fail to Compile if the Platform's Renderer Can't Be Moved onto a Render Thread
+const _: fn() = || {
+    fn spawnable<T: CommandTarget + Send + 'static>() {}
+    spawnable::<Renderer<backend::Backend>>();
+};
+
+/// Runs a Renderer on its Own Thread, Driven by Commands.
+///
+/// Window events must still be handled on the main thread, as winit
+/// requires; only the GPU work moves. The window the renderer's surface
+/// was created from must outlive the render thread.
+///
+/// Dropping the handle stops the thread and drops the renderer there.
+pub struct RenderThread {
+    commands: Option<Sender<RenderCommand>>,
+    errors: Receiver<RenderError>,
+    frames_rendered: Arc<AtomicU64>,
+    handle: Option<JoinHandle<()>>
+}
+
+impl RenderThread {
+    /// Move a Renderer onto a New Thread.
+    pub fn spawn<T: CommandTarget + Send + 'static>(mut target: T) -> Self {
+        let (command_sender, command_receiver) = mpsc::channel();
+        let (error_sender, error_receiver) = mpsc::channel();
+
+        let frames_rendered = Arc::new(AtomicU64::new(0));
+        let frame_counter = Arc::clone(&frames_rendered);
+
+        let handle = thread::spawn(move || {
+            run_commands(&mut target, command_receiver, error_sender, &frame_counter);
+        });
+
+        Self {
+            commands: Some(command_sender),
+            errors: error_receiver,
+            frames_rendered,
+            handle: Some(handle)
+        }
+    }
+
+    /// Queue a Command for the Render Thread.
+    pub fn send(&self, command: RenderCommand) {
+        if let Some(commands) = &self.commands {
+            commands.send(command).expect("Render thread stopped unexpectedly");
+        }
+    }
+
+    /// Get the Next Error Reported by the Render Thread, if Any.
+    pub fn try_recv_error(&self) -> Option<RenderError> {
+        self.errors.try_recv().ok()
+    }
+
+    /// Get How Many Frames Have Been Presented.
+    pub fn frames_rendered(&self) -> u64 {
+        self.frames_rendered.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for RenderThread {
+    fn drop(&mut self) {
+        // Closing the Channel Ends the Thread's Command Loop
+        self.commands = None;
+
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Execute Commands Until Every Sender is Dropped.
+fn run_commands<T: CommandTarget>(
+    target: &mut T,
+    commands: Receiver<RenderCommand>,
+    errors: Sender<RenderError>,
+    frames_rendered: &AtomicU64
+) {
+    for command in commands {
+        match target.execute(command) {
+            Ok(true) => { frames_rendered.fetch_add(1, Ordering::Relaxed); },
+            Ok(false) => (),
+            Err(error) => {
+                // The Receiver May Already Be Gone
+                let _ = errors.send(error);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+
+    use super::*;
+
+    /// Records Commands, Failing Every Render Once `fail_renders` is Set.
+    struct MockTarget {
+        executed: Sender<RenderCommand>,
+        fail_renders: bool,
+        dropped: Arc<AtomicBool>
+    }
+
+    impl CommandTarget for MockTarget {
+        fn execute(&mut self, command: RenderCommand) -> Result<bool, RenderError> {
+            let is_render = command == RenderCommand::Render;
+            self.executed.send(command).unwrap();
+
+            match (is_render, self.fail_renders) {
+                (true, true) => Err(RenderError::SurfaceLost { failed_frames: 1 }),
+                (true, false) => Ok(true),
+                (false, _) => Ok(false)
+            }
+        }
+    }
+
+    impl Drop for MockTarget {
+        fn drop(&mut self) {
+            self.dropped.store(true, Ordering::SeqCst);
+        }
+    }
+
+    fn mock_target(fail_renders: bool) -> (MockTarget, Receiver<RenderCommand>, Arc<AtomicBool>) {
+        let (executed, executed_receiver) = mpsc::channel();
+        let dropped = Arc::new(AtomicBool::new(false));
+
+        let target = MockTarget { executed, fail_renders, dropped: Arc::clone(&dropped) };
+        (target, executed_receiver, dropped)
+    }
+
+    /// Run the Command Loop on this Thread Until the Commands Run Out.
+    fn run(target: &mut MockTarget, commands: &[RenderCommand]) -> (u64, Vec<RenderError>) {
+        let (command_sender, command_receiver) = mpsc::channel();
+        let (error_sender, error_receiver) = mpsc::channel();
+        let frames_rendered = AtomicU64::new(0);
+
+        for command in commands {
+            command_sender.send(command.clone()).unwrap();
+        }
+        drop(command_sender);
+
+        run_commands(target, command_receiver, error_sender, &frames_rendered);
+
+        (frames_rendered.into_inner(), error_receiver.try_iter().collect())
+    }
+
+    #[test]
+    fn commands_execute_in_order() {
+        let (mut target, executed, _) = mock_target(false);
+        let commands = [
+            RenderCommand::Resize([640, 480]),
+            RenderCommand::PushScissor(Rect { x: 0, y: 0, w: 10, h: 10 }),
+            RenderCommand::Render,
+            RenderCommand::PopScissor,
+            RenderCommand::Render
+        ];
+
+        let (frames, errors) = run(&mut target, &commands);
+
+        assert_eq!(executed.try_iter().collect::<Vec<_>>(), commands);
+        assert_eq!(frames, 2);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn render_errors_are_reported() {
+        let (mut target, _executed, _) = mock_target(true);
+
+        let (frames, errors) = run(&mut target, &[RenderCommand::Render, RenderCommand::Render]);
+
+        assert_eq!(frames, 0);
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn drop_stops_thread_and_drops_target() {
+        let (target, executed, dropped) = mock_target(false);
+
+        let render_thread = RenderThread::spawn(target);
+        render_thread.send(RenderCommand::SetClearDepth(0.5));
+        render_thread.send(RenderCommand::Render);
+        drop(render_thread);
+
+        // Dropping Joined the Thread, so Queued Commands Have Run
+        assert!(dropped.load(Ordering::SeqCst));
+        assert_eq!(
+            executed.try_iter().collect::<Vec<_>>(),
+            [RenderCommand::SetClearDepth(0.5), RenderCommand::Render]);
+    }
+}
@@ -1,5 +1,8 @@
 
 use std::iter;
+use std::mem;
+use std::ops::Range;
+use std::path::PathBuf;
 
 use raw_window_handle::HasRawWindowHandle;
 
@@ -7,18 +10,87 @@ use shaderc::ShaderKind;
 
 use gfx_hal::{
     Instance,
-    window::{Surface, PresentationSurface, Extent2D},
+    window::{Surface, PresentationSurface, Extent2D, PresentMode, CompositeAlphaMode},
     adapter::Adapter,
     device::Device,
     queue::family::QueueGroup
 };
 
-use super::compile_shader::compile_shader;
+use super::color::{self, ColorSpace};
+use super::compile_shader::{compile_shader_variant, ShaderSource};
+use super::config::{RendererConfig, BlendMode};
+use super::error::{CreationError, RenderError, DeviceLostReason};
+use super::scissor::{self, ScissorStack};
+use super::retry_budget::RetryBudget;
+use super::depth;
+use super::attachment::AttachmentImage;
+use super::mesh::{self, Mesh};
+use super::vertex::Vertex;
+use super::pipeline_cache;
+use super::memory::MemoryReport;
+
+/// A 4x4 Matrix Stored as Four Columns, Matching GLSL's `mat4`.
+pub type Matrix4 = [[f32; 4]; 4];
+
+const IDENTITY_MATRIX: Matrix4 = [
+    [1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+    [0.0, 0.0, 0.0, 1.0]
+];
+
+// What the Pipeline is Built From, Kept to Rebuild it at a Lower Sample Count
+struct PipelineSource {
+    vertex_binary: Vec<u32>,
+    fragment_binary: Vec<u32>,
+    cull_face: gfx_hal::pso::Face,
+    front_face: gfx_hal::pso::FrontFace,
+    blend_mode: BlendMode
+}
 
 pub struct Renderer<B: gfx_hal::Backend> {
     resources: Option<Resources<B>>,
     surface_extent: Extent2D,
-    should_configure_swapchain: bool
+    should_configure_swapchain: bool,
+
+    // The Surface is Released Between `suspend` and `resume`
+    suspended: bool,
+    scissor_stack: ScissorStack,
+    // Already Converted for the Color Format
+    clear_color: [f32; 4],
+    clear_depth: f32,
+
+    // Frames Presented Successfully
+    frame_index: u64,
+
+    // Consecutive Frames that Failed to Acquire, Present or Finish in Time
+    surface_retry: RetryBudget,
+
+    // Consecutive Frames the GPU Took Too Long to Finish
+    fence_retry: RetryBudget,
+
+    // Where the Pipeline Cache is Saved on Drop
+    pipeline_cache_path: Option<PathBuf>,
+
+    device_lost_callback: Option<Box<dyn FnMut(DeviceLostReason) + Send>>,
+
+    viewport_depth: Range<f32>,
+
+    // Pushed to the Vertex Shader with Each Draw
+    model_matrix: Matrix4,
+
+    // Replay Draws from a Secondary Command Buffer Instead of Recording Them Each Frame
+    use_static_commands: bool,
+    // The Viewport, Scissor and Model Matrix the Static Draws were Recorded With
+    static_commands_state: Option<(gfx_hal::pso::Viewport, gfx_hal::pso::Rect, Matrix4)>,
+
+    vsync: bool,
+    // Takes Precedence over Vsync When Set
+    requested_present_mode: Option<PresentMode>,
+    // The Mode the Swapchain was Last Configured With
+    present_mode: Option<PresentMode>,
+
+    composite_alpha_mode: CompositeAlphaMode
 }
 
 struct Resources<B: gfx_hal::Backend> {
@@ -27,14 +99,31 @@ struct Resources<B: gfx_hal::Backend> {
     pub device: B::Device,
     pub adapter: Adapter<B>,
 
+    // Features the Device was Opened With
+    pub features: gfx_hal::Features,
+
     pub color_format: gfx_hal::format::Format,
+    pub depth_format: gfx_hal::format::Format,
+    pub samples: gfx_hal::image::NumSamples,
+
+    // Created When the Swapchain is Configured
+    pub swapchain_attachment: Option<gfx_hal::image::FramebufferAttachment>,
+    // The color buffer only exists when multisampling, and is resolved into the swapchain image.
+    pub color_buffer: Option<AttachmentImage<B>>,
+    pub depth_buffer: Option<AttachmentImage<B>>,
+
+    pub mesh: Mesh<B>,
 
     pub render_passes: Vec<B::RenderPass>,
     pub pipeline_layouts: Vec<B::PipelineLayout>,
     pub pipelines: Vec<B::GraphicsPipeline>,
+    pub pipeline_cache: B::PipelineCache,
+    pub pipeline_source: PipelineSource,
 
     pub command_pool: B::CommandPool,
     pub command_buffer: B::CommandBuffer,
+    pub static_command_pool: B::CommandPool,
+    pub static_command_buffer: B::CommandBuffer,
     pub queue_group: QueueGroup<B>,
 
     pub submission_complete_fence: B::Fence,
@@ -42,13 +131,37 @@ struct Resources<B: gfx_hal::Backend> {
 }
 
 impl<B: gfx_hal::Backend> Renderer<B> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         app_name: &str,
         physical_size: [u32; 2],
         window: &impl HasRawWindowHandle,
-        vertex_shader: &str,
-        fragment_shader: &str
-    ) -> Self {
+        vertex_shader: ShaderSource,
+        fragment_shader: ShaderSource,
+        vertices: &[Vertex],
+        indices: &[u16],
+        config: RendererConfig
+    ) -> Result<Self, CreationError> {
+
+        // Compile the Configured Shader Variant
+        // Done First so a Shader Error Fails Before Any GPU Objects Exist
+        let (vertex_binary, fragment_binary) = {
+            let defines: Vec<(&str, Option<&str>)> = config.shader_defines
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_deref()))
+                .collect();
+
+            let include_paths = &config.shader_include_paths;
+
+            let compile = |source: ShaderSource, kind| compile_shader_variant(
+                    source.text, kind, source.file_name, &defines, include_paths)
+                .map_err(CreationError::ShaderCompilation);
+
+            (compile(vertex_shader, ShaderKind::Vertex)?, compile(fragment_shader, ShaderKind::Fragment)?)
+        };
+
+        // Check the Geometry Before Any GPU Objects Exist Too
+        mesh::validate(vertices, indices).map_err(CreationError::InvalidMesh)?;
 
         // Set Up Access to the Graphics Backend
         let (instance, surface, adapter) = {
@@ -73,8 +186,17 @@ impl<B: gfx_hal::Backend> Renderer<B> {
         };
 
         // Set Up a Logical Device
-        let (device, queue_group) = {
+        let (device, queue_group, features) = {
             use gfx_hal::queue::family::QueueFamily;
+            use gfx_hal::adapter::PhysicalDevice;
+
+            // Check the Adapter Supports Every Required Feature
+            let features = config.features;
+            let missing_features = features - adapter.physical_device.features();
+
+            if !missing_features.is_empty() {
+                return Err(CreationError::MissingFeatures(missing_features));
+            }
 
             // Find a Compatible QueueFamily
             let queue_family = adapter
@@ -86,17 +208,20 @@ impl<B: gfx_hal::Backend> Renderer<B> {
                 })
                 .expect("No compatible queue family found");
 
+            // Check the Family Can Provide the Requested Queues
+            // Rendering Uses the First Queue
+            let priorities = &config.queue_priorities;
+            check_queue_priorities(priorities, queue_family.max_queues())?;
+
             // Create a Logical Device
             let mut gpu = unsafe {
-                use gfx_hal::adapter::PhysicalDevice;
-
                 adapter.physical_device
-                    .open(&[(queue_family, &[1.0])], gfx_hal::Features::empty())
+                    .open(&[(queue_family, priorities)], features)
                     .expect("Failed to open device")
             };
 
             // GPU holds a Logical Device and its Queue Groups.
-            (gpu.device, gpu.queue_groups.pop().unwrap())
+            (gpu.device, gpu.queue_groups.pop().unwrap(), features)
         };
 
         // Set Up a Command Buffer
@@ -113,108 +238,185 @@ impl<B: gfx_hal::Backend> Renderer<B> {
             (command_pool, command_buffer)
         };
 
-        // Find an SRGB Color Format Compatible with the Surface
+        // Set Up a Secondary Command Buffer for Static Draws
+        // It Has its Own Pool so Resetting the Per-Frame Pool Keeps it Intact
+        let (static_command_pool, static_command_buffer) = unsafe {
+            use gfx_hal::pool::{CommandPool, CommandPoolCreateFlags};
+            use gfx_hal::command::Level;
+
+            let mut command_pool = device
+                .create_command_pool(queue_group.family, CommandPoolCreateFlags::empty())
+                .expect("Out of memory");
+
+            let command_buffer = command_pool.allocate_one(Level::Secondary);
+
+            (command_pool, command_buffer)
+        };
+
+        // Find a Color Format Compatible with the Surface
         let color_format = {
             use gfx_hal::format::{ChannelType, Format};
 
             // Get All Compatible Color Formats
-            let supported_formats = surface
-                .supported_formats(&adapter.physical_device)
-                .unwrap_or(vec![]);
+            // None Means the Surface Accepts Any Format
+            let supported_formats = surface.supported_formats(&adapter.physical_device);
+
+            // Use the Preferred Format if the Surface Supports It
+            let preferred_override = config.preferred_color_format.filter(|format| {
+                let is_supported = match &supported_formats {
+                    Some(formats) => formats.contains(format),
+                    None => true
+                };
+
+                if !is_supported {
+                    eprintln!(
+                        "Warning: color format {:?} is not supported by the surface; \
+                        falling back to automatic selection", format);
+                }
 
-            // Set the Default to Preferred Format
-            let preferred = supported_formats.get(0);
+                is_supported
+            });
 
-            // If No Preferred Format, Choose Whatever
-            let default = *(preferred.unwrap_or(&Format::Rgba8Srgb));
+            preferred_override.unwrap_or_else(|| {
+                let supported_formats = supported_formats.unwrap_or(vec![]);
 
-            // Find an SRGB Color Format or Use the Default
-            supported_formats
-                .into_iter()
-                .find(|format| format.base_format().1 == ChannelType::Srgb)
-                .unwrap_or(default)
+                // Set the Default to Preferred Format
+                let preferred = supported_formats.get(0);
+
+                // If No Preferred Format, Choose Whatever
+                let default = *(preferred.unwrap_or(&Format::Rgba8Srgb));
+
+                // Find an SRGB Color Format or Use the Default
+                supported_formats
+                    .into_iter()
+                    .find(|format| format.base_format().1 == ChannelType::Srgb)
+                    .unwrap_or(default)
+            })
         };
 
-        // Create a Render Pass
-        let render_pass = {
-            use gfx_hal::pass::{
-                Attachment, AttachmentOps,
-                AttachmentLoadOp, AttachmentStoreOp,
-                SubpassDesc
-            };
-            use gfx_hal::image::Layout;
-
-            // Describe an Attachment
-            let color_attachment = Attachment {
-                format: Some(color_format),
-                samples: 1,
-                ops: AttachmentOps::new(
-                    AttachmentLoadOp::Clear,
-                    AttachmentStoreOp::Store
-                ),
-                stencil_ops: AttachmentOps::DONT_CARE,
-                layouts: Layout::Undefined..Layout::Present
-            };
+        // Check the Model Matrix Fits in the Adapter's Push Constants
+        {
+            use gfx_hal::adapter::PhysicalDevice;
 
-            // Describe a Subpass
-            let subpass = SubpassDesc {
-                colors: &[(0, Layout::ColorAttachmentOptimal)],
-                depth_stencil: None,
-                inputs: &[],
-                resolves: &[],
-                preserves: &[]
-            };
+            let required = mem::size_of::<Matrix4>();
+            let max = adapter.physical_device.limits().max_push_constants_size;
 
-            // Create a RenderPass with the Descriptions
-            unsafe {
-                device
-                    .create_render_pass(
-                        iter::once(color_attachment),
-                        iter::once(subpass),
-                        iter::empty())
-                    .expect("Out of memory")
+            if required > max {
+                return Err(CreationError::PushConstantsTooLarge { required, max });
             }
+        }
+
+        // Find How the Surface Composites with the Windows Behind It
+        let composite_alpha_mode = Self::select_composite_alpha_mode(
+            config.composite_alpha_mode,
+            surface.capabilities(&adapter.physical_device).composite_alpha_modes);
+
+        // Find a Depth Format the Adapter Supports
+        let depth_format = depth::select_format::<B>(&adapter.physical_device)
+            .ok_or(CreationError::NoDepthFormat)?;
+
+        // Find a Sample Count Both the Color and Depth Attachments Support
+        let samples = {
+            use gfx_hal::adapter::PhysicalDevice;
+            use gfx_hal::image::{Tiling, Usage, ViewCapabilities};
+
+            let supported_samples = |format, usage| adapter.physical_device
+                .image_format_properties(
+                    format, 2, Tiling::Optimal, usage, ViewCapabilities::empty())
+                .map_or(1, |properties| properties.sample_count_mask);
+
+            let color_usage = Usage::COLOR_ATTACHMENT | Usage::TRANSIENT_ATTACHMENT;
+            let depth_usage = Usage::DEPTH_STENCIL_ATTACHMENT;
+
+            select_sample_count(
+                config.samples,
+                supported_samples(color_format, color_usage) & supported_samples(depth_format, depth_usage))
+        };
+
+        // Create a Render Pass
+        let render_pass = unsafe {
+            Self::make_render_pass(&device, color_format, depth_format, samples)
         };
 
         // Create a Pipeline Layout
+        // The Vertex Stage Receives the Model Matrix as a Push Constant
         let pipeline_layout = unsafe {
+            use gfx_hal::pso::ShaderStageFlags;
+
+            let model_matrix_range = 0..mem::size_of::<Matrix4>() as u32;
+
             device
-                .create_pipeline_layout(iter::empty(), iter::empty())
+                .create_pipeline_layout(
+                    iter::empty(),
+                    iter::once((ShaderStageFlags::VERTEX, model_matrix_range)))
                 .expect("Out of memory")
         };
 
+        // Reuse Compiled Pipelines from Previous Runs
+        let pipeline_cache = unsafe {
+            pipeline_cache::load::<B>(
+                &device,
+                &adapter.physical_device,
+                config.pipeline_cache_path.as_deref())
+        };
+
+        let pipeline_source = PipelineSource {
+            vertex_binary,
+            fragment_binary,
+            cull_face: config.cull_face,
+            front_face: config.front_face,
+            blend_mode: config.blend_mode
+        };
+
         // Create a Pipeline
         let pipeline = unsafe {
             Self::make_pipeline(
                 &device,
                 &render_pass,
                 &pipeline_layout,
-                vertex_shader,
-                fragment_shader
+                &pipeline_cache,
+                &pipeline_source,
+                samples
             )
         };
 
+        // Upload the Geometry
+        let mesh = unsafe { Mesh::new(&device, &adapter, vertices, indices) };
+
         // Syncs CPU to GPU
         let submission_complete_fence = device.create_fence(true).expect("Out of memory");
         // Syncs Internal GPU Processes
         let rendering_complete_semaphore = device.create_semaphore().expect("Out of memory");
 
-        Self {
+        Ok(Self {
             resources: Some(Resources {
                 instance,
                 surface,
                 device,
                 adapter,
 
+                features,
+
                 color_format,
+                depth_format,
+                samples,
+                swapchain_attachment: None,
+                color_buffer: None,
+                depth_buffer: None,
+
+                mesh,
 
                 command_pool,
                 command_buffer,
+                static_command_pool,
+                static_command_buffer,
                 queue_group,
 
                 render_passes: vec![render_pass],
                 pipeline_layouts: vec![pipeline_layout],
                 pipelines: vec![pipeline],
+                pipeline_cache,
+                pipeline_source,
 
                 submission_complete_fence,
                 rendering_complete_semaphore
@@ -223,27 +425,132 @@ impl<B: gfx_hal::Backend> Renderer<B> {
                 width: physical_size[0],
                 height: physical_size[1]
             },
-            should_configure_swapchain: true
-        }
+            should_configure_swapchain: true,
+            suspended: false,
+            scissor_stack: ScissorStack::default(),
+            clear_color: color::clear_value(config.clear_color, config.clear_color_space, color_format),
+            clear_depth: config.clear_depth,
+            frame_index: 0,
+            surface_retry: RetryBudget::new(config.surface_retry_budget),
+            fence_retry: RetryBudget::new(config.fence_timeout_budget),
+            pipeline_cache_path: config.pipeline_cache_path,
+            device_lost_callback: None,
+            viewport_depth: 0.0..1.0,
+            model_matrix: IDENTITY_MATRIX,
+            use_static_commands: config.static_commands,
+            static_commands_state: None,
+            vsync: config.vsync,
+            requested_present_mode: config.present_mode,
+            present_mode: None,
+            composite_alpha_mode
+        })
+    }
+
+    /// Create a Render Pass Drawing Color and Depth at the Given Sample Count.
+    /// When Multisampled, Color is Resolved into the Swapchain Image as a Third Attachment.
+    unsafe fn make_render_pass(
+        device: &B::Device,
+        color_format: gfx_hal::format::Format,
+        depth_format: gfx_hal::format::Format,
+        samples: gfx_hal::image::NumSamples
+    ) -> B::RenderPass {
+        use gfx_hal::pass::{
+            Attachment, AttachmentOps,
+            AttachmentLoadOp, AttachmentStoreOp,
+            SubpassDesc
+        };
+        use gfx_hal::image::Layout;
+
+        let multisampled = samples > 1;
+
+        // Describe an Attachment
+        // A Multisampled Color Attachment is Only Needed Within the Pass
+        let (color_store_op, color_final_layout) = if multisampled {
+            (AttachmentStoreOp::DontCare, Layout::ColorAttachmentOptimal)
+        } else {
+            (AttachmentStoreOp::Store, Layout::Present)
+        };
+
+        let color_attachment = Attachment {
+            format: Some(color_format),
+            samples,
+            ops: AttachmentOps::new(
+                AttachmentLoadOp::Clear,
+                color_store_op
+            ),
+            stencil_ops: AttachmentOps::DONT_CARE,
+            layouts: Layout::Undefined..color_final_layout
+        };
+
+        // Describe a Depth Attachment
+        // Depth is Only Needed Within the Pass
+        let depth_attachment = Attachment {
+            format: Some(depth_format),
+            samples,
+            ops: AttachmentOps::new(
+                AttachmentLoadOp::Clear,
+                AttachmentStoreOp::DontCare
+            ),
+            stencil_ops: AttachmentOps::DONT_CARE,
+            layouts: Layout::Undefined..Layout::DepthStencilAttachmentOptimal
+        };
+
+        // Describe the Swapchain Image Multisampled Color is Resolved Into
+        // The multisampled attachment shares the swapchain's format, so with an sRGB
+        // swapchain both are sRGB and no views reinterpret the encoding. D3D12, Metal and
+        // Vulkan (where the spec recommends it) resolve sRGB formats by decoding samples to
+        // linear, averaging, then re-encoding, so edges blend in linear space.
+        let resolve_attachment = Attachment {
+            format: Some(color_format),
+            samples: 1,
+            ops: AttachmentOps::new(
+                AttachmentLoadOp::DontCare,
+                AttachmentStoreOp::Store
+            ),
+            stencil_ops: AttachmentOps::DONT_CARE,
+            layouts: Layout::Undefined..Layout::Present
+        };
+
+        // Describe a Subpass
+        let subpass = SubpassDesc {
+            colors: &[(0, Layout::ColorAttachmentOptimal)],
+            depth_stencil: Some(&(1, Layout::DepthStencilAttachmentOptimal)),
+            inputs: &[],
+            resolves: if multisampled { &[(2, Layout::ColorAttachmentOptimal)] } else { &[] },
+            preserves: &[]
+        };
+
+        let attachments = iter::once(color_attachment)
+            .chain(iter::once(depth_attachment))
+            .chain(Some(resolve_attachment).filter(|_| multisampled));
+
+        // Create a RenderPass with the Descriptions
+        device
+            .create_render_pass(
+                attachments,
+                iter::once(subpass),
+                iter::empty())
+            .expect("Out of memory")
     }
 
-    /// Create and Return a Pipeline.
+    /// Create and Return a Pipeline from Compiled SPIR-V.
     unsafe fn make_pipeline(
         device: &B::Device,
         render_pass: &B::RenderPass,
         pipeline_layout: &B::PipelineLayout,
-        vertex_shader: &str,
-        fragment_shader: &str,
+        pipeline_cache: &B::PipelineCache,
+        source: &PipelineSource,
+        samples: gfx_hal::image::NumSamples
     ) -> B::GraphicsPipeline {
         use gfx_hal::pass::Subpass;
         
         // Create Shader Object Modules
         let vertex_shader_module = device
-            .create_shader_module(&compile_shader(vertex_shader, ShaderKind::Vertex))
+            .create_shader_module(&source.vertex_binary)
             .expect("Failed to create vertex shader module");
 
         let fragment_shader_module = device
-            .create_shader_module(&compile_shader(fragment_shader, ShaderKind::Fragment))
+            .create_shader_module(&source.fragment_binary)
             .expect("Failed to create fragment shader module");
 
         use gfx_hal::pso::{
@@ -269,8 +576,8 @@ impl<B: gfx_hal::Backend> Renderer<B> {
         // Describe the Primitive Assembler
         // A Primitive Assembler Transforms Input into Primitives
         let primitive_assembler = PrimitiveAssemblerDesc::Vertex {
-            buffers: &[],
-            attributes: &[],
+            buffers: &[Vertex::buffer_desc()],
+            attributes: &Vertex::attribute_descs(),
             input_assembler: InputAssemblerDesc::new(Primitive::TriangleList),
             vertex: vertex_shader_entry,
             tessellation: None,
@@ -278,13 +585,14 @@ impl<B: gfx_hal::Backend> Renderer<B> {
         };
 
         use gfx_hal::pso::{
-            GraphicsPipelineDesc, Rasterizer, Face
+            GraphicsPipelineDesc, Rasterizer
         };
         // Describe the Pipeline
         let mut pipeline_desc = GraphicsPipelineDesc::new(
             primitive_assembler,
             Rasterizer {
-                cull_face: Face::BACK,
+                cull_face: source.cull_face,
+                front_face: source.front_face,
                 ..Rasterizer::FILL
             },
             Some(fragment_shader_entry),
@@ -296,17 +604,42 @@ impl<B: gfx_hal::Backend> Renderer<B> {
         );
 
         use gfx_hal::pso::{
-            ColorBlendDesc, ColorMask, BlendState
+            DepthStencilDesc, DepthTest, Comparison
+        };
+        // Keep the Nearest Fragment
+        pipeline_desc.depth_stencil = DepthStencilDesc {
+            depth: Some(DepthTest {
+                fun: Comparison::LessEqual,
+                write: true
+            }),
+            depth_bounds: false,
+            stencil: None
+        };
+
+        use gfx_hal::pso::Multisampling;
+        // Rasterize at the Render Pass's Sample Count
+        if samples > 1 {
+            pipeline_desc.multisampling = Some(Multisampling {
+                rasterization_samples: samples,
+                sample_shading: None,
+                sample_mask: !0,
+                alpha_coverage: false,
+                alpha_to_one: false
+            });
+        }
+
+        use gfx_hal::pso::{
+            ColorBlendDesc, ColorMask
         };
-        // Set Blend Mode to Alpha Blend
+        // Set the Configured Blend Mode
         pipeline_desc.blender.targets.push(ColorBlendDesc {
             mask: ColorMask::ALL,
-            blend: Some(BlendState::ALPHA)
+            blend: source.blend_mode.blend_state()
         });
 
         // Create the Pipeline
         let pipeline = device
-            .create_graphics_pipeline(&pipeline_desc, None)
+            .create_graphics_pipeline(&pipeline_desc, Some(pipeline_cache))
             .expect("Failed to create graphics pipeline");
 
         // Clean Up Shader Object Modules
@@ -321,90 +654,577 @@ impl<B: gfx_hal::Backend> Renderer<B> {
             width: physical_size[0],
             height: physical_size[1]
         };
+        self.recreate_swapchain();
+    }
+
+    /// Rebuild the Swapchain at the Current Size on the Next `configure_swapchain` or `render`,
+    /// e.g. After a Display Mode Change. The Depth Buffer is Kept if its Size Still Matches.
+    pub fn recreate_swapchain(&mut self) {
         self.should_configure_swapchain = true;
     }
 
-    pub fn render(&mut self) {
-        let res: &mut Resources<_> = self.resources.as_mut().unwrap();
-        let render_pass = &res.render_passes[0];
-        let pipeline = &res.pipelines[0];
+    /// Map Depth Values into a Sub-Range of the Depth Buffer.
+    /// E.g. Drawing a HUD into `0.0..0.01` Keeps it in Front of the Scene.
+    ///
+    /// The range must satisfy `0.0 <= near <= far <= 1.0`. Otherwise a
+    /// warning is printed and the current range is kept.
+    pub fn set_viewport_depth(&mut self, near: f32, far: f32) {
+        if !is_valid_depth_range(near, far) {
+            eprintln!(
+                "Warning: viewport depth must satisfy 0.0 <= near <= far <= 1.0, got {}..{}; ignoring",
+                near, far);
+            return;
+        }
+
+        self.viewport_depth = near..far;
+    }
+
+    /// Turn Vsync On or Off. Only the Swapchain is Reconfigured.
+    /// Replaces any mode requested with `set_present_mode`.
+    pub fn set_vsync(&mut self, vsync: bool) {
+        if self.vsync != vsync || self.requested_present_mode.is_some() {
+            self.vsync = vsync;
+            self.requested_present_mode = None;
+            self.recreate_swapchain();
+        }
+    }
+
+    /// Present with a Specific Mode, e.g. Mailbox for Benchmarking.
+    /// FIFO is used instead if the surface doesn't support the mode.
+    /// Only the Swapchain is Reconfigured.
+    pub fn set_present_mode(&mut self, mode: PresentMode) {
+        if self.requested_present_mode != Some(mode) {
+            self.requested_present_mode = Some(mode);
+            self.recreate_swapchain();
+        }
+    }
+
+    /// Get the Present Mode the Swapchain is Using, Once Configured.
+    pub fn present_mode(&self) -> Option<PresentMode> {
+        self.present_mode
+    }
+
+    /// Use the Requested Mode if Supported, Falling Back to FIFO, Which All Surfaces Support.
+    /// Without a Request, Choose FIFO for Vsync, Otherwise Mailbox or Immediate if Supported.
+    fn select_present_mode(
+        vsync: bool,
+        requested: Option<PresentMode>,
+        supported: PresentMode
+    ) -> PresentMode {
+        if let Some(requested) = requested {
+            return if supported.contains(requested) {
+                requested
+            } else {
+                PresentMode::FIFO
+            };
+        }
+
+        if vsync {
+            return PresentMode::FIFO;
+        }
+
+        if supported.contains(PresentMode::MAILBOX) {
+            PresentMode::MAILBOX
+        } else if supported.contains(PresentMode::IMMEDIATE) {
+            PresentMode::IMMEDIATE
+        } else {
+            eprintln!("Warning: surface does not support disabling vsync; using FIFO");
+            PresentMode::FIFO
+        }
+    }
+
+    /// Use the Requested Alpha Mode if Supported, Otherwise Fall Back to Opaque.
+    /// Without a Request, Inherit the Platform's Mode Where Possible.
+    /// If Neither Preference is Supported, Use Any Mode the Surface Supports.
+    fn select_composite_alpha_mode(
+        requested: Option<CompositeAlphaMode>,
+        supported: CompositeAlphaMode
+    ) -> CompositeAlphaMode {
+        use CompositeAlphaMode as Mode;
+
+        if let Some(requested) = requested {
+            if supported.contains(requested) {
+                return requested;
+            }
+        }
+
+        let preferences = if requested.is_some() {
+            [Mode::OPAQUE, Mode::INHERIT, Mode::PREMULTIPLIED, Mode::POSTMULTIPLIED]
+        } else {
+            [Mode::INHERIT, Mode::OPAQUE, Mode::PREMULTIPLIED, Mode::POSTMULTIPLIED]
+        };
+
+        // Every Surface Supports at Least One Mode
+        let mode = preferences
+            .iter()
+            .copied()
+            .find(|mode| supported.contains(*mode))
+            .unwrap_or(Mode::OPAQUE);
+
+        if let Some(requested) = requested {
+            eprintln!(
+                "Warning: surface does not support alpha mode {:?}; using {:?}", requested, mode);
+        }
+
+        mode
+    }
+
+    /// Get the Alpha Modes the Surface Supports.
+    pub fn supported_composite_alpha_modes(&self) -> CompositeAlphaMode {
+        let res = self.resources.as_ref().unwrap();
+
+        res.surface.capabilities(&res.adapter.physical_device).composite_alpha_modes
+    }
+
+    /// Get the Alpha Mode the Swapchain is Configured With.
+    pub fn composite_alpha_mode(&self) -> CompositeAlphaMode {
+        self.composite_alpha_mode
+    }
+
+    /// Returns False While the Surface Has No Area, e.g. When Minimized, While Suspended,
+    /// or While the Swapchain Needs Reconfiguring. Call `configure_swapchain` First to Clear the Latter.
+    pub fn is_renderable(&self) -> bool {
+        !self.suspended && self.has_surface_area() && !self.should_configure_swapchain
+    }
+
+    fn has_surface_area(&self) -> bool {
+        self.surface_extent.width > 0 && self.surface_extent.height > 0
+    }
+
+    /// Release the Swapchain When the Application is Suspended.
+    /// Some platforms invalidate the window's surface until `resume`.
+    pub fn suspend(&mut self) {
+        if self.suspended {
+            return;
+        }
+
+        let res = self.resources.as_mut().unwrap();
 
-        // Wait Until Previous Draw Commands Are Submitted
         unsafe {
-            use gfx_hal::pool::CommandPool;
+            // The GPU May Still Be Presenting
+            res.device.wait_idle().expect("Out of memory");
+            res.surface.unconfigure_swapchain(&res.device);
+        }
 
-            // We refuse to wait more than a second, to avoid hanging.
-            const RENDER_TIMEOUT_NS: u64 = 1_000_000_000;
+        self.suspended = true;
+    }
 
-            res.device
-                .wait_for_fence(&res.submission_complete_fence, RENDER_TIMEOUT_NS)
-                .expect("Out of memory or device lost");
+    /// Recreate the Surface for the Window After `suspend`.
+    /// The window may be a new one if the platform replaced it.
+    pub fn resume(&mut self, window: &impl HasRawWindowHandle) {
+        if !self.suspended {
+            return;
+        }
 
-            res.device
-                .reset_fence(&mut res.submission_complete_fence)
-                .expect("Out of memory");
+        let res = self.resources.as_mut().unwrap();
 
-            res.command_pool.reset(false);
+        unsafe {
+            let surface = res.instance
+                .create_surface(window)
+                .expect("Failed to create surface for window");
+
+            let old_surface = mem::replace(&mut res.surface, surface);
+            res.instance.destroy_surface(old_surface);
         }
 
-        // Update Swapchain if Needed
-        // Get Framebuffer Attachment from Swapchain
-        let framebuffer_attachment = {
-            use gfx_hal::window::SwapchainConfig;
+        self.suspended = false;
+        self.recreate_swapchain();
+    }
+
+    /// Returns True Until `configure_swapchain` or `render` Reconfigures the Swapchain.
+    pub fn needs_swapchain_configuration(&self) -> bool {
+        self.should_configure_swapchain
+    }
 
-            // Get Supported Swapchain Capabilities
-            let caps = res.surface.capabilities(&res.adapter.physical_device);
+    /// Clip Subsequent Draws to a Rectangle in Physical Pixels.
+    /// The Rectangle is Intersected with the Current Scissor.
+    pub fn push_scissor(&mut self, rect: gfx_hal::pso::Rect) {
+        self.scissor_stack.push(rect);
+    }
 
-            // Create a Swapchain Configuration
-            let mut swapchain_config =
-                SwapchainConfig::from_caps(&caps, res.color_format, self.surface_extent);
+    /// Restore the Scissor that was Active Before the Last Push.
+    pub fn pop_scissor(&mut self) -> Option<gfx_hal::pso::Rect> {
+        self.scissor_stack.pop()
+    }
 
-            // Fix Fullscreen Slowdown on MacOS.
-            if caps.image_count.contains(&3) {
-                swapchain_config.image_count = 3;
-            }
+    /// Get the Features the Device was Opened With.
+    /// These are exactly `RendererConfig::features`.
+    pub fn enabled_features(&self) -> gfx_hal::Features {
+        self.resources.as_ref().unwrap().features
+    }
+
+    /// Get the Resource Limits the Device was Opened With.
+    /// gfx-hal has no way to request limits, so these are always the adapter's own.
+    pub fn enabled_limits(&self) -> gfx_hal::Limits {
+        use gfx_hal::adapter::PhysicalDevice;
+
+        self.resources.as_ref().unwrap().adapter.physical_device.limits()
+    }
+
+    /// Get the Samples per Pixel Rendered With.
+    /// 1 if multisampling is disabled or the requested count wasn't supported.
+    /// May drop after a resize if the multisampled buffers don't fit in memory.
+    pub fn samples(&self) -> gfx_hal::image::NumSamples {
+        self.resources.as_ref().unwrap().samples
+    }
+
+    /// Get the Depth Format Chosen for the Adapter.
+    pub fn depth_format(&self) -> gfx_hal::format::Format {
+        self.resources.as_ref().unwrap().depth_format
+    }
+
+    /// Get Approximate GPU Memory Information. See `MemoryReport` for What's Populated.
+    pub fn memory_report(&self) -> MemoryReport {
+        use gfx_hal::adapter::PhysicalDevice;
+
+        let res = self.resources.as_ref().unwrap();
+
+        let renderer_allocated = res.mesh.size()
+            + res.color_buffer.as_ref().map_or(0, |color_buffer| color_buffer.size)
+            + res.depth_buffer.as_ref().map_or(0, |depth_buffer| depth_buffer.size);
+
+        MemoryReport::new(
+            &res.adapter.physical_device.memory_properties().memory_heaps,
+            renderer_allocated)
+    }
+
+    /// Set the Matrix the Vertex Shader Transforms the Mesh By.
+    /// Each inner array is a column, as in GLSL.
+    pub fn set_model_matrix(&mut self, matrix: Matrix4) {
+        self.model_matrix = matrix;
+    }
+
+    /// Set the RGBA Color the Screen is Cleared to Each Frame.
+    /// The color is converted from its color space to match the color format,
+    /// so a linear color matches the same value output by a shader.
+    pub fn set_clear_color(&mut self, color: [f32; 4], space: ColorSpace) {
+        let color_format = self.resources.as_ref().unwrap().color_format;
+
+        self.clear_color = color::clear_value(color, space, color_format);
+    }
+
+    /// Set the Value the Depth Buffer is Cleared to Each Frame.
+    pub fn set_clear_depth(&mut self, depth: f32) {
+        self.clear_depth = depth;
+    }
+
+    /// Record the Frame's Draws Into a Command Buffer Inside the Render Pass.
+    /// Sets All Dynamic State, Since Secondary Buffers Don't Inherit It.
+    unsafe fn record_draws(
+        command_buffer: &mut B::CommandBuffer,
+        pipeline: &B::GraphicsPipeline,
+        pipeline_layout: &B::PipelineLayout,
+        mesh: &Mesh<B>,
+        model_matrix: &Matrix4,
+        viewport: &gfx_hal::pso::Viewport,
+        scissor: gfx_hal::pso::Rect
+    ) {
+        use gfx_hal::buffer::SubRange;
+        use gfx_hal::command::CommandBuffer;
+        use gfx_hal::pso::ShaderStageFlags;
+        use gfx_hal::IndexType;
+
+        command_buffer.set_viewports(0, iter::once(viewport.clone()));
+        command_buffer.set_scissors(0, iter::once(scissor));
+
+        command_buffer.bind_graphics_pipeline(pipeline);
+
+        command_buffer.bind_vertex_buffers(
+            0,
+            iter::once((&mesh.vertex_buffer.buffer, SubRange::WHOLE)));
+        command_buffer.bind_index_buffer(
+            &mesh.index_buffer.buffer,
+            SubRange::WHOLE,
+            IndexType::U16);
+
+        // Push Constants are Passed as 32 Bit Words
+        let model_matrix_words: Vec<u32> = model_matrix
+            .iter()
+            .flatten()
+            .map(|value| value.to_bits())
+            .collect();
+
+        command_buffer.push_graphics_constants(
+            pipeline_layout,
+            ShaderStageFlags::VERTEX,
+            0,
+            &model_matrix_words);
+
+        // Draw the Mesh, Unless It's Fully Clipped
+        if !scissor::is_empty(&scissor) {
+            command_buffer.draw_indexed(0..mesh.index_count, 0, 0..1);
+        }
+    }
+
+    /// Get How Many Frames Have Been Presented.
+    /// Only advances when `render` succeeds; skipped and failed frames don't count,
+    /// so it can key per-frame effects like jitter sequences.
+    pub fn frame_index(&self) -> u64 {
+        self.frame_index
+    }
+
+    /// Count a Frame the Surface Failed to Produce.
+    /// Gives Up Once the Retry Budget is Spent.
+    fn record_failed_frame(&mut self) -> Result<(), RenderError> {
+        self.recreate_swapchain();
+        self.surface_retry
+            .fail()
+            .map_err(|failed_frames| RenderError::SurfaceLost { failed_frames })
+    }
 
-            // Update new Window Size
-            self.surface_extent = swapchain_config.extent;
+    /// Count a Frame Skipped Because the Previous One Hadn't Finished.
+    /// Gives Up Once the Timeout Budget is Spent.
+    fn record_fence_timeout(&mut self) -> Result<(), RenderError> {
+        self.fence_retry
+            .fail()
+            .map_err(|timed_out_frames| RenderError::FrameTimeout { timed_out_frames })
+    }
+
+    /// Call a Function When the Device is Found to Be Lost.
+    ///
+    /// The callback runs on whichever thread calls `render`, just before
+    /// `render` returns `RenderError::DeviceLost`. The renderer is borrowed
+    /// while it runs, so it can't call back into the renderer; it should
+    /// record the loss (or save state) and let the caller recreate the renderer.
+    pub fn on_device_lost(&mut self, callback: Box<dyn FnMut(DeviceLostReason) + Send>) {
+        self.device_lost_callback = Some(callback);
+    }
 
-            let fat = swapchain_config.framebuffer_attachment();
+    /// Notify the Device Lost Callback and Produce the Matching Error.
+    fn device_lost(&mut self, reason: DeviceLostReason) -> RenderError {
+        if let Some(callback) = self.device_lost_callback.as_mut() {
+            callback(reason);
+        }
+
+        RenderError::DeviceLost(reason)
+    }
 
-            // Configure the Swapchain with the new Configuration
-            if self.should_configure_swapchain {
-                unsafe {
-                    res.surface
-                        .configure_swapchain(&res.device, swapchain_config)
-                        .expect("Failed to configure swapchain");
+    /// Reconfigure the Swapchain Now if it Needs It, Instead of in the Next `render`.
+    /// Call After a Resize and Before `is_renderable`. Does Nothing While Suspended or Minimized.
+    pub fn configure_swapchain(&mut self) -> Result<(), RenderError> {
+        if !self.should_configure_swapchain || self.suspended || !self.has_surface_area() {
+            return Ok(());
+        }
+
+        // The Buffers Being Replaced May Still Be in Use
+        if self.wait_for_previous_frame()? {
+            unsafe { self.apply_swapchain_configuration(); }
+        }
+
+        Ok(())
+    }
+
+    /// Wait Until the GPU is Done With the Previous Frame.
+    /// Returns False if it Timed Out Within the Budget, and the Frame Should Be Skipped.
+    fn wait_for_previous_frame(&mut self) -> Result<bool, RenderError> {
+        use gfx_hal::device::WaitError;
+
+        // We refuse to wait more than a second, to avoid hanging.
+        const RENDER_TIMEOUT_NS: u64 = 1_000_000_000;
+
+        let res = self.resources.as_ref().unwrap();
+
+        match unsafe { res.device.wait_for_fence(&res.submission_complete_fence, RENDER_TIMEOUT_NS) } {
+            Ok(true) => Ok(true),
+            // Timed Out; the GPU May Still Be Using the Command Buffer
+            Ok(false) => self.record_fence_timeout().map(|()| false),
+            Err(WaitError::DeviceLost(_)) =>
+                Err(self.device_lost(DeviceLostReason::FenceWait)),
+            Err(WaitError::OutOfMemory(_)) => panic!("Out of memory")
+        }
+    }
+
+    /// Configure the Swapchain for the Current Size and Present Mode.
+    /// The caller must have waited for the previous frame to finish.
+    unsafe fn apply_swapchain_configuration(&mut self) {
+        use gfx_hal::window::SwapchainConfig;
+
+        let res: &mut Resources<_> = self.resources.as_mut().unwrap();
+
+        // Get Supported Swapchain Capabilities
+        let caps = res.surface.capabilities(&res.adapter.physical_device);
+
+        // Create a Swapchain Configuration
+        let mut swapchain_config =
+            SwapchainConfig::from_caps(&caps, res.color_format, self.surface_extent)
+                .with_composite_alpha_mode(self.composite_alpha_mode);
+
+        // Fix Fullscreen Slowdown on MacOS.
+        if caps.image_count.contains(&3) {
+            swapchain_config.image_count = 3;
+        }
+
+        // Update new Window Size
+        self.surface_extent = swapchain_config.extent;
+
+        res.swapchain_attachment = Some(swapchain_config.framebuffer_attachment());
+
+        swapchain_config.present_mode = Self::select_present_mode(
+            self.vsync,
+            self.requested_present_mode,
+            caps.present_modes);
+        self.present_mode = Some(swapchain_config.present_mode);
+
+        res.surface
+            .configure_swapchain(&res.device, swapchain_config)
+            .expect("Failed to configure swapchain");
+
+        // Recreate the Depth and Color Buffers if the Size Changed
+        let depth_extent = res.depth_buffer.as_ref().map(|depth_buffer| depth_buffer.extent);
+
+        if depth_extent != Some(self.surface_extent) {
+            use gfx_hal::image::Usage;
+
+            if let Some(color_buffer) = res.color_buffer.take() {
+                color_buffer.destroy(&res.device);
+            }
+            if let Some(depth_buffer) = res.depth_buffer.take() {
+                depth_buffer.destroy(&res.device);
+            }
+
+            // Both Buffers Must Share a Sample Count, so Neither is Kept if the Other Fails
+            let extent = self.surface_extent;
+            let create_buffers = |samples| {
+                let color_buffer = if samples > 1 {
+                    Some(AttachmentImage::new(
+                        &res.device,
+                        &res.adapter,
+                        res.color_format,
+                        Usage::COLOR_ATTACHMENT | Usage::TRANSIENT_ATTACHMENT,
+                        extent,
+                        samples)?)
+                } else {
+                    None
                 };
 
-                self.should_configure_swapchain = false;
+                let depth_buffer = AttachmentImage::new(
+                    &res.device,
+                    &res.adapter,
+                    res.depth_format,
+                    Usage::DEPTH_STENCIL_ATTACHMENT,
+                    extent,
+                    samples);
+
+                match depth_buffer {
+                    Ok(depth_buffer) => Ok((color_buffer, depth_buffer)),
+                    Err(error) => {
+                        if let Some(color_buffer) = color_buffer {
+                            color_buffer.destroy(&res.device);
+                        }
+                        Err(error)
+                    }
+                }
+            };
+
+            // Multisampled Buffers Take Several Times the Memory, so Trade Quality for Fitting
+            let ((color_buffer, depth_buffer), samples) =
+                allocate_with_fallback(res.samples, create_buffers).expect("Out of memory");
+
+            res.color_buffer = color_buffer;
+            res.depth_buffer = Some(depth_buffer);
+
+            if samples != res.samples {
+                Self::rebuild_for_samples(res, samples);
+                // The Static Draws were Recorded Against the Old Render Pass
+                self.static_commands_state = None;
             }
+        }
 
-            fat
-        };
+        self.should_configure_swapchain = false;
+    }
+
+    /// Replace the Render Pass and Pipeline with Ones Drawing at a New Sample Count.
+    /// The GPU must not be using the old ones.
+    unsafe fn rebuild_for_samples(res: &mut Resources<B>, samples: gfx_hal::image::NumSamples) {
+        let render_pass = Self::make_render_pass(&res.device, res.color_format, res.depth_format, samples);
+
+        let pipeline = Self::make_pipeline(
+            &res.device,
+            &render_pass,
+            &res.pipeline_layouts[0],
+            &res.pipeline_cache,
+            &res.pipeline_source,
+            samples);
+
+        let old_pipeline = mem::replace(&mut res.pipelines[0], pipeline);
+        res.device.destroy_graphics_pipeline(old_pipeline);
+
+        let old_render_pass = mem::replace(&mut res.render_passes[0], render_pass);
+        res.device.destroy_render_pass(old_render_pass);
+
+        res.samples = samples;
+    }
+
+    /// Draw and Present a Frame.
+    /// Fails if the Surface Stays Unusable for Too Many Frames in a Row.
+    pub fn render(&mut self) -> Result<(), RenderError> {
+        // Wait Until Previous Draw Commands Are Submitted
+        if !self.wait_for_previous_frame()? {
+            return Ok(());
+        }
+
+        // Update Swapchain if Needed
+        if self.should_configure_swapchain {
+            unsafe { self.apply_swapchain_configuration(); }
+        }
+
+        let res: &mut Resources<_> = self.resources.as_mut().unwrap();
+        let render_pass = &res.render_passes[0];
+        let pipeline_layout = &res.pipeline_layouts[0];
+        let pipeline = &res.pipelines[0];
+
+        unsafe {
+            use gfx_hal::pool::CommandPool;
+
+            res.command_pool.reset(false);
+        }
+
+        // Get Framebuffer Attachment from Swapchain
+        let framebuffer_attachment = res.swapchain_attachment.clone().unwrap();
 
         // Get Image From Swapchain
         let surface_image = unsafe {
+            use gfx_hal::window::AcquireError;
+
             let acquire_timeout_ns = 1_000_000_000;
 
             match res.surface.acquire_image(acquire_timeout_ns) {
                 Ok((image, _)) => image,
-                Err(_) => {
-                    self.should_configure_swapchain = true;
-                    return;
-                }
+                Err(AcquireError::DeviceLost(_)) =>
+                    return Err(self.device_lost(DeviceLostReason::AcquireImage)),
+                Err(_) => return self.record_failed_frame()
             }
         };
 
+        // Only Unsignal the Fence Once the Frame Will Be Submitted
+        // Otherwise the next wait would time out on a fence nothing signals.
+        unsafe {
+            res.device
+                .reset_fence(&mut res.submission_complete_fence)
+                .expect("Out of memory");
+        }
+
         // Create a FrameBuffer
         // A FrameBuffer Stores an Image to Fill an Attachment
         let framebuffer = unsafe {
             use gfx_hal::image::Extent;
 
+            let depth_attachment = res.depth_buffer.as_ref().unwrap().framebuffer_attachment();
+
+            // In the Render Pass's Order, with the Swapchain Image Last When Resolving Into It
+            let attachments = match &res.color_buffer {
+                Some(color_buffer) => vec![
+                    color_buffer.framebuffer_attachment(),
+                    depth_attachment,
+                    framebuffer_attachment
+                ],
+                None => vec![framebuffer_attachment, depth_attachment]
+            };
+
             res.device
                 .create_framebuffer(
                     render_pass,
-                    iter::once(framebuffer_attachment),
+                    attachments.into_iter(),
                     Extent {
                         width: self.surface_extent.width,
                         height: self.surface_extent.height,
@@ -425,51 +1245,134 @@ impl<B: gfx_hal::Backend> Renderer<B> {
                     w: self.surface_extent.width as i16,
                     h: self.surface_extent.height as i16
                 },
-                depth: 0.0..1.0
+                depth: self.viewport_depth.clone()
             }
         };
 
+        // Clip to the Current Scissor, Clamped to the Frame
+        let scissor = self.scissor_stack.current(viewport.rect);
+
+        // Re-Record the Static Draws if the State They Captured Changed
+        // The Fence Wait Above Ensures the GPU is Done with the Old Recording
+        let static_state = Some((viewport.clone(), scissor, self.model_matrix));
+
+        if self.use_static_commands && self.static_commands_state != static_state {
+            unsafe {
+                use gfx_hal::pool::CommandPool;
+                use gfx_hal::command::{
+                    CommandBuffer, CommandBufferFlags, CommandBufferInheritanceInfo
+                };
+                use gfx_hal::pass::Subpass;
+
+                res.static_command_pool.reset(false);
+
+                res.static_command_buffer.begin(
+                    CommandBufferFlags::RENDER_PASS_CONTINUE,
+                    CommandBufferInheritanceInfo {
+                        subpass: Some(Subpass {
+                            index: 0,
+                            main_pass: render_pass
+                        }),
+                        ..CommandBufferInheritanceInfo::default()
+                    });
+
+                Self::record_draws(
+                    &mut res.static_command_buffer,
+                    pipeline,
+                    pipeline_layout,
+                    &res.mesh,
+                    &self.model_matrix,
+                    &viewport,
+                    scissor);
+
+                res.static_command_buffer.finish();
+            }
+
+            self.static_commands_state = static_state;
+        }
+
         // Line Up Draw Commands
         unsafe {
             use std::borrow::Borrow;
 
             use gfx_hal::command::{
                 CommandBuffer, CommandBufferFlags,
-                RenderAttachmentInfo, ClearValue, ClearColor, SubpassContents
+                RenderAttachmentInfo, ClearValue, ClearColor, ClearDepthStencil,
+                SubpassContents
             };
 
+            let depth_buffer = res.depth_buffer.as_ref().unwrap();
+
             res.command_buffer.begin_primary(CommandBufferFlags::ONE_TIME_SUBMIT);
 
-            res.command_buffer.set_viewports(0, iter::once(viewport.clone()));
-            res.command_buffer.set_scissors(0, iter::once(viewport.rect));
+            let contents = if self.use_static_commands {
+                SubpassContents::SecondaryBuffers
+            } else {
+                SubpassContents::Inline
+            };
+
+            // Clear to the Configured Color and Depth
+            let color_clear = ClearValue {
+                color: ClearColor {
+                    float32: self.clear_color
+                }
+            };
+
+            let depth_info = RenderAttachmentInfo {
+                image_view: &depth_buffer.view,
+                clear_value: ClearValue {
+                    depth_stencil: ClearDepthStencil {
+                        depth: self.clear_depth,
+                        stencil: 0
+                    }
+                }
+            };
+
+            let surface_info = RenderAttachmentInfo {
+                image_view: surface_image.borrow(),
+                clear_value: color_clear
+            };
+
+            // A Resolved Swapchain Image Isn't Cleared, so its Clear Value is Unused
+            let attachments = match &res.color_buffer {
+                Some(color_buffer) => vec![
+                    RenderAttachmentInfo {
+                        image_view: &color_buffer.view,
+                        clear_value: color_clear
+                    },
+                    depth_info,
+                    surface_info
+                ],
+                None => vec![surface_info, depth_info]
+            };
 
-            // Clear to Black
             res.command_buffer.begin_render_pass(
                 render_pass,
                 &framebuffer,
                 viewport.rect,
-                iter::once(RenderAttachmentInfo {
-                    image_view: surface_image.borrow(),
-                    clear_value: ClearValue {
-                        color: ClearColor {
-                            float32: [0.0, 0.0, 0.0, 1.0]
-                        }
-                    }
-                }),
-                SubpassContents::Inline
+                attachments.into_iter(),
+                contents
             );
 
-            res.command_buffer.bind_graphics_pipeline(pipeline);
-
-            // Draw a Triangle
-            res.command_buffer.draw(0..3, 0..1);
+            if self.use_static_commands {
+                res.command_buffer.execute_commands(iter::once(&res.static_command_buffer));
+            } else {
+                Self::record_draws(
+                    &mut res.command_buffer,
+                    pipeline,
+                    pipeline_layout,
+                    &res.mesh,
+                    &self.model_matrix,
+                    &viewport,
+                    scissor);
+            }
 
             res.command_buffer.end_render_pass();
             res.command_buffer.finish();
         }
 
         // Execute Draw Commands and Present
-        unsafe {
+        let presented = unsafe {
             use gfx_hal::queue::CommandQueue;
 
             // Submit Commands to be Executed
@@ -487,21 +1390,106 @@ impl<B: gfx_hal::Backend> Renderer<B> {
                 Some(&mut res.rendering_complete_semaphore),
             );
 
-            self.should_configure_swapchain |= result.is_err();
-
             res.device.destroy_framebuffer(framebuffer);
+
+            result
+        };
+
+        match presented {
+            Ok(_) => (),
+            Err(gfx_hal::window::PresentError::DeviceLost(_)) =>
+                return Err(self.device_lost(DeviceLostReason::Present)),
+            Err(_) => return self.record_failed_frame()
+        }
+
+        self.frame_index += 1;
+        self.surface_retry.succeed();
+        self.fence_retry.succeed();
+        Ok(())
+    }
+}
+
+/// Use the Requested Samples per Pixel if Supported, Otherwise Fall Back to One.
+/// `supported` Has a Bit Set for Each Supported Count.
+fn select_sample_count(
+    requested: gfx_hal::image::NumSamples,
+    supported: gfx_hal::image::NumSamples
+) -> gfx_hal::image::NumSamples {
+    if requested == 1 || (requested.is_power_of_two() && supported & requested != 0) {
+        return requested;
+    }
+
+    eprintln!(
+        "Warning: adapter does not support {} samples per pixel; disabling multisampling", requested);
+    1
+}
+
+/// Call `allocate` at the Given Sample Count, Halving it After Each Failure Down to 1.
+/// Returns What was Allocated and the Sample Count that Fit, or the Last Error.
+fn allocate_with_fallback<T, E>(
+    samples: gfx_hal::image::NumSamples,
+    mut allocate: impl FnMut(gfx_hal::image::NumSamples) -> Result<T, E>
+) -> Result<(T, gfx_hal::image::NumSamples), E> {
+    let mut samples = samples;
+
+    loop {
+        match allocate(samples) {
+            Ok(allocated) => return Ok((allocated, samples)),
+            Err(error) if samples <= 1 => return Err(error),
+            Err(_) => {
+                let lower = samples / 2;
+                eprintln!(
+                    "Warning: out of memory for {} samples per pixel; falling back to {}", samples, lower);
+                samples = lower;
+            }
         }
     }
 }
 
+/// Returns True if a Viewport Can Map Depth to `near..far`.
+/// NaN Fails Every Comparison, so is Never Valid.
+fn is_valid_depth_range(near: f32, far: f32) -> bool {
+    0.0 <= near && near <= far && far <= 1.0
+}
+
+/// Check a Queue Family Can Open a Queue for Each Priority, and Each is Valid.
+fn check_queue_priorities(priorities: &[f32], max_queues: usize) -> Result<(), CreationError> {
+    if priorities.is_empty() || priorities.len() > max_queues {
+        return Err(CreationError::InvalidQueueCount {
+            requested: priorities.len(),
+            max: max_queues
+        });
+    }
+
+    // NaN is Never Within the Range
+    match priorities.iter().position(|priority| !(0.0..=1.0).contains(priority)) {
+        Some(index) => Err(CreationError::InvalidQueuePriority {
+            index,
+            priority: priorities[index]
+        }),
+        None => Ok(())
+    }
+}
+
 impl<B: gfx_hal::Backend> Drop for Renderer<B> {
     fn drop(&mut self) {
         let mut r = Option::take(&mut self.resources).unwrap();
 
+        // Let the GPU Finish Before Destroying What it Uses
+        // Panicking here would abort if already unwinding, so only warn.
+        if let Err(error) = r.device.wait_idle() {
+            eprintln!("Warning: failed to wait for the device to idle: {}", error);
+        }
+
         unsafe {
             r.device.destroy_semaphore(r.rendering_complete_semaphore);
             r.device.destroy_fence(r.submission_complete_fence);
 
+            if let Some(path) = &self.pipeline_cache_path {
+                pipeline_cache::save::<B>(&r.device, &r.pipeline_cache, path);
+            }
+            r.device.destroy_pipeline_cache(r.pipeline_cache);
+
             for pipeline in r.pipelines {
                 r.device.destroy_graphics_pipeline(pipeline);
             }
@@ -512,9 +1500,252 @@ impl<B: gfx_hal::Backend> Drop for Renderer<B> {
                 r.device.destroy_render_pass(render_pass);
             }
 
+            if let Some(color_buffer) = r.color_buffer {
+                color_buffer.destroy(&r.device);
+            }
+            if let Some(depth_buffer) = r.depth_buffer {
+                depth_buffer.destroy(&r.device);
+            }
+            r.mesh.destroy(&r.device);
+
+            r.device.destroy_command_pool(r.static_command_pool);
             r.device.destroy_command_pool(r.command_pool);
             r.surface.unconfigure_swapchain(&r.device);
             r.instance.destroy_surface(r.surface);
         };
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type TestRenderer = Renderer<backend::Backend>;
+
+    const ALL_PRESENT_MODES: PresentMode = PresentMode::all();
+
+    #[test]
+    fn vsync_uses_fifo() {
+        assert_eq!(
+            TestRenderer::select_present_mode(true, None, ALL_PRESENT_MODES),
+            PresentMode::FIFO);
+    }
+
+    #[test]
+    fn without_vsync_mailbox_is_preferred_over_immediate() {
+        assert_eq!(
+            TestRenderer::select_present_mode(false, None, ALL_PRESENT_MODES),
+            PresentMode::MAILBOX);
+
+        assert_eq!(
+            TestRenderer::select_present_mode(
+                false, None, PresentMode::FIFO | PresentMode::IMMEDIATE),
+            PresentMode::IMMEDIATE);
+    }
+
+    #[test]
+    fn without_vsync_falls_back_to_fifo() {
+        assert_eq!(
+            TestRenderer::select_present_mode(false, None, PresentMode::FIFO),
+            PresentMode::FIFO);
+    }
+
+    #[test]
+    fn supported_request_is_used() {
+        assert_eq!(
+            TestRenderer::select_present_mode(
+                false, Some(PresentMode::RELAXED), ALL_PRESENT_MODES),
+            PresentMode::RELAXED);
+    }
+
+    #[test]
+    fn unsupported_request_falls_back_to_fifo() {
+        assert_eq!(
+            TestRenderer::select_present_mode(
+                false, Some(PresentMode::MAILBOX), PresentMode::FIFO | PresentMode::IMMEDIATE),
+            PresentMode::FIFO);
+    }
+
+    #[test]
+    fn request_takes_precedence_over_vsync() {
+        assert_eq!(
+            TestRenderer::select_present_mode(
+                true, Some(PresentMode::IMMEDIATE), ALL_PRESENT_MODES),
+            PresentMode::IMMEDIATE);
+    }
+
+    #[test]
+    fn supported_alpha_request_is_used() {
+        assert_eq!(
+            TestRenderer::select_composite_alpha_mode(
+                Some(CompositeAlphaMode::PREMULTIPLIED), CompositeAlphaMode::all()),
+            CompositeAlphaMode::PREMULTIPLIED);
+    }
+
+    #[test]
+    fn unsupported_alpha_request_falls_back_to_opaque() {
+        assert_eq!(
+            TestRenderer::select_composite_alpha_mode(
+                Some(CompositeAlphaMode::POSTMULTIPLIED),
+                CompositeAlphaMode::OPAQUE | CompositeAlphaMode::INHERIT),
+            CompositeAlphaMode::OPAQUE);
+    }
+
+    #[test]
+    fn alpha_without_request_prefers_inherit() {
+        assert_eq!(
+            TestRenderer::select_composite_alpha_mode(None, CompositeAlphaMode::all()),
+            CompositeAlphaMode::INHERIT);
+
+        assert_eq!(
+            TestRenderer::select_composite_alpha_mode(None, CompositeAlphaMode::OPAQUE),
+            CompositeAlphaMode::OPAQUE);
+    }
+
+    #[test]
+    fn alpha_fallback_is_always_supported() {
+        // Neither the Request Nor Opaque are Supported
+        assert_eq!(
+            TestRenderer::select_composite_alpha_mode(
+                Some(CompositeAlphaMode::POSTMULTIPLIED), CompositeAlphaMode::INHERIT),
+            CompositeAlphaMode::INHERIT);
+
+        assert_eq!(
+            TestRenderer::select_composite_alpha_mode(
+                Some(CompositeAlphaMode::OPAQUE), CompositeAlphaMode::PREMULTIPLIED),
+            CompositeAlphaMode::PREMULTIPLIED);
+
+        assert_eq!(
+            TestRenderer::select_composite_alpha_mode(None, CompositeAlphaMode::POSTMULTIPLIED),
+            CompositeAlphaMode::POSTMULTIPLIED);
+    }
+
+    /// Needs a Display and a GPU, so Only Runs with `cargo test -- --ignored`.
+    #[test]
+    #[ignore]
+    #[cfg(all(unix, not(target_os = "macos")))]
+    fn enabled_limits_allow_push_constants() {
+        use winit::{event_loop::EventLoop, platform::unix::EventLoopExtUnix, window::WindowBuilder};
+
+        let event_loop = EventLoop::<()>::new_any_thread();
+        let window = WindowBuilder::new()
+            .with_visible(false)
+            .build(&event_loop)
+            .unwrap();
+
+        let vertices = [
+            Vertex { position: [0.0, -0.5, 0.0], color: [1.0, 0.0, 0.0] },
+            Vertex { position: [-0.5, 0.5, 0.0], color: [0.0, 1.0, 0.0] },
+            Vertex { position: [0.5, 0.5, 0.0], color: [0.0, 0.0, 1.0] }
+        ];
+
+        let renderer = TestRenderer::new(
+                "Renderer Test",
+                window.inner_size().into(),
+                &window,
+                ShaderSource {
+                    file_name: "shaders/part-1.vert",
+                    text: include_str!("../shaders/part-1.vert")
+                },
+                ShaderSource {
+                    file_name: "shaders/part-1.frag",
+                    text: include_str!("../shaders/part-1.frag")
+                },
+                &vertices,
+                &[0, 1, 2],
+                RendererConfig::default())
+            .unwrap();
+
+        // Creation Checked the Model Matrix Fits
+        assert!(renderer.enabled_limits().max_push_constants_size >= mem::size_of::<Matrix4>());
+        assert_eq!(renderer.enabled_features(), RendererConfig::default().features);
+    }
+
+    #[test]
+    fn queue_priorities_must_fit_the_family() {
+        assert!(check_queue_priorities(&[1.0, 0.5], 2).is_ok());
+
+        for (priorities, max_queues) in &[(&[][..], 4), (&[1.0, 1.0, 1.0][..], 2)] {
+            match check_queue_priorities(priorities, *max_queues) {
+                Err(CreationError::InvalidQueueCount { requested, max }) => {
+                    assert_eq!(requested, priorities.len());
+                    assert_eq!(max, *max_queues);
+                },
+                other => panic!("expected InvalidQueueCount, got {:?}", other)
+            }
+        }
+    }
+
+    #[test]
+    fn queue_priorities_must_be_in_range() {
+        assert!(check_queue_priorities(&[0.0, 1.0], 2).is_ok());
+
+        for priority in &[-0.1, 1.5, f32::NAN] {
+            match check_queue_priorities(&[1.0, *priority], 2) {
+                Err(CreationError::InvalidQueuePriority { index: 1, .. }) => (),
+                other => panic!("expected InvalidQueuePriority for {}, got {:?}", priority, other)
+            }
+        }
+    }
+
+    #[test]
+    fn depth_range_within_unit_interval_is_valid() {
+        assert!(is_valid_depth_range(0.0, 1.0));
+        assert!(is_valid_depth_range(0.0, 0.01));
+        assert!(is_valid_depth_range(0.5, 0.5));
+    }
+
+    #[test]
+    fn invalid_depth_ranges_are_rejected() {
+        assert!(!is_valid_depth_range(0.8, 0.2));
+        assert!(!is_valid_depth_range(-0.1, 0.5));
+        assert!(!is_valid_depth_range(0.5, 1.1));
+        assert!(!is_valid_depth_range(f32::NAN, 1.0));
+        assert!(!is_valid_depth_range(0.0, f32::NAN));
+    }
+
+    #[test]
+    fn supported_sample_count_is_used() {
+        assert_eq!(select_sample_count(4, 0b0101), 4);
+        assert_eq!(select_sample_count(1, 0b0101), 1);
+    }
+
+    #[test]
+    fn unsupported_sample_count_falls_back_to_one() {
+        assert_eq!(select_sample_count(8, 0b0101), 1);
+        assert_eq!(select_sample_count(3, 0b0111), 1);
+        assert_eq!(select_sample_count(0, 0b0101), 1);
+    }
+
+    #[test]
+    fn allocation_failure_lowers_the_sample_count() {
+        let mut attempts = Vec::new();
+
+        // Only 2 Samples per Pixel Fit
+        let result = allocate_with_fallback(8, |samples| {
+            attempts.push(samples);
+            if samples > 2 { Err("out of memory") } else { Ok(samples) }
+        });
+
+        assert_eq!(result, Ok((2, 2)));
+        assert_eq!(attempts, [8, 4, 2]);
+    }
+
+    #[test]
+    fn successful_allocation_keeps_the_sample_count() {
+        assert_eq!(allocate_with_fallback(4, |_| Ok::<_, ()>(())), Ok(((), 4)));
+    }
+
+    #[test]
+    fn allocation_failure_without_multisampling_is_returned() {
+        let mut attempts = Vec::new();
+
+        let result = allocate_with_fallback(4, |samples| {
+            attempts.push(samples);
+            Err::<(), _>("out of memory")
+        });
+
+        assert_eq!(result, Err("out of memory"));
+        assert_eq!(attempts, [4, 2, 1]);
+    }
+}
@@ -0,0 +1,69 @@
+
+/// Counts Consecutive Failures, Giving Up Once the Budget is Spent.
+/// Any success starts the count over.
+#[derive(Debug, Clone)]
+pub struct RetryBudget {
+    failures: u32,
+    budget: u32
+}
+
+impl RetryBudget {
+    pub fn new(budget: u32) -> Self {
+        Self { failures: 0, budget }
+    }
+
+    /// Count a Failure.
+    /// Returns the Number of Consecutive Failures Once the Budget is Spent.
+    pub fn fail(&mut self) -> Result<(), u32> {
+        self.failures = self.failures.saturating_add(1);
+
+        if self.failures >= self.budget {
+            Err(self.failures)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Forget Earlier Failures After a Success.
+    pub fn succeed(&mut self) {
+        self.failures = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failures_within_budget_are_retried() {
+        let mut retry = RetryBudget::new(3);
+
+        assert_eq!(retry.fail(), Ok(()));
+        assert_eq!(retry.fail(), Ok(()));
+    }
+
+    #[test]
+    fn repeated_failures_spend_the_budget() {
+        let mut retry = RetryBudget::new(3);
+        retry.fail().unwrap();
+        retry.fail().unwrap();
+
+        assert_eq!(retry.fail(), Err(3));
+        assert_eq!(retry.fail(), Err(4));
+    }
+
+    #[test]
+    fn success_resets_the_count() {
+        let mut retry = RetryBudget::new(2);
+        retry.fail().unwrap();
+        retry.succeed();
+
+        assert_eq!(retry.fail(), Ok(()));
+        assert_eq!(retry.fail(), Err(2));
+    }
+
+    #[test]
+    fn zero_budget_fails_immediately() {
+        assert_eq!(RetryBudget::new(0).fail(), Err(1));
+    }
+}
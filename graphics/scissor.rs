@@ -0,0 +1,129 @@
+
+use gfx_hal::pso::Rect;
+
+/// A Stack of Nested Clipping Rectangles.
+///
+/// Each pushed rectangle is intersected with its parent,
+/// so the top of the stack is always the effective clip.
+#[derive(Debug, Clone, Default)]
+pub struct ScissorStack {
+    rects: Vec<Rect>
+}
+
+impl ScissorStack {
+    pub fn push(&mut self, rect: Rect) {
+        let clipped = match self.rects.last() {
+            Some(parent) => intersect(parent, &rect),
+            None => rect
+        };
+
+        self.rects.push(clipped);
+    }
+
+    pub fn pop(&mut self) -> Option<Rect> {
+        self.rects.pop()
+    }
+
+    /// Get the Effective Scissor Clamped to the Frame Bounds.
+    pub fn current(&self, bounds: Rect) -> Rect {
+        match self.rects.last() {
+            Some(rect) => intersect(rect, &bounds),
+            None => bounds
+        }
+    }
+}
+
+/// Returns True if Nothing Can Be Drawn Through the Rectangle.
+pub fn is_empty(rect: &Rect) -> bool {
+    rect.w <= 0 || rect.h <= 0
+}
+
+/// Intersect Two Rectangles, Producing a Zero-Area Rectangle if They Don't Overlap.
+fn intersect(a: &Rect, b: &Rect) -> Rect {
+    // Widen to Avoid Overflow at the Edges of i16
+    let left = i32::max(a.x as i32, b.x as i32);
+    let top = i32::max(a.y as i32, b.y as i32);
+    let right = i32::min(a.x as i32 + a.w as i32, b.x as i32 + b.w as i32);
+    let bottom = i32::min(a.y as i32 + a.h as i32, b.y as i32 + b.h as i32);
+
+    Rect {
+        x: left as i16,
+        y: top as i16,
+        w: i32::max(right - left, 0) as i16,
+        h: i32::max(bottom - top, 0) as i16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: i16, y: i16, w: i16, h: i16) -> Rect {
+        Rect { x, y, w, h }
+    }
+
+    const BOUNDS: Rect = Rect { x: 0, y: 0, w: 800, h: 600 };
+
+    #[test]
+    fn empty_stack_uses_frame_bounds() {
+        assert_eq!(ScissorStack::default().current(BOUNDS), BOUNDS);
+    }
+
+    #[test]
+    fn nested_push_intersects_with_parent() {
+        let mut stack = ScissorStack::default();
+        stack.push(rect(100, 100, 200, 200));
+        stack.push(rect(250, 50, 200, 100));
+
+        assert_eq!(stack.current(BOUNDS), rect(250, 100, 50, 50));
+    }
+
+    #[test]
+    fn pop_restores_parent() {
+        let mut stack = ScissorStack::default();
+        stack.push(rect(100, 100, 200, 200));
+        stack.push(rect(150, 150, 10, 10));
+
+        assert_eq!(stack.pop(), Some(rect(150, 150, 10, 10)));
+        assert_eq!(stack.current(BOUNDS), rect(100, 100, 200, 200));
+
+        assert_eq!(stack.pop(), Some(rect(100, 100, 200, 200)));
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.current(BOUNDS), BOUNDS);
+    }
+
+    #[test]
+    fn current_is_clamped_to_frame_bounds() {
+        let mut stack = ScissorStack::default();
+        stack.push(rect(-50, 500, 200, 200));
+
+        assert_eq!(stack.current(BOUNDS), rect(0, 500, 150, 100));
+    }
+
+    #[test]
+    fn disjoint_rects_produce_zero_area() {
+        let mut stack = ScissorStack::default();
+        stack.push(rect(0, 0, 100, 100));
+        stack.push(rect(200, 200, 100, 100));
+
+        let current = stack.current(BOUNDS);
+        assert_eq!((current.w, current.h), (0, 0));
+        assert!(is_empty(&current));
+    }
+
+    #[test]
+    fn is_empty_requires_positive_area() {
+        assert!(is_empty(&rect(10, 10, 0, 10)));
+        assert!(is_empty(&rect(10, 10, 10, 0)));
+        assert!(is_empty(&rect(10, 10, -5, 10)));
+        assert!(!is_empty(&rect(10, 10, 1, 1)));
+    }
+
+    #[test]
+    fn intersect_does_not_overflow_at_i16_edges() {
+        let wide = rect(i16::MAX - 10, 0, i16::MAX, 10);
+        let clipped = intersect(&wide, &rect(i16::MAX - 5, 0, 5, 10));
+
+        assert_eq!(clipped, rect(i16::MAX - 5, 0, 5, 10));
+    }
+}
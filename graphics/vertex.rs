@@ -0,0 +1,50 @@
+
+use std::mem;
+
+use gfx_hal::{
+    format::Format,
+    pso::{AttributeDesc, Element, VertexBufferDesc, VertexInputRate}
+};
+
+/// A Vertex as Laid Out in the Vertex Buffer.
+///
+/// Shaders receive `position` at location 0 and `color` at location 1.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub color: [f32; 3]
+}
+
+impl Vertex {
+    /// Describe the Vertex Buffer Bound at Binding 0.
+    pub fn buffer_desc() -> VertexBufferDesc {
+        VertexBufferDesc {
+            binding: 0,
+            stride: mem::size_of::<Vertex>() as u32,
+            rate: VertexInputRate::Vertex
+        }
+    }
+
+    /// Describe Each Field's Shader Location and Offset.
+    pub fn attribute_descs() -> [AttributeDesc; 2] {
+        [
+            AttributeDesc {
+                location: 0,
+                binding: 0,
+                element: Element {
+                    format: Format::Rgb32Sfloat,
+                    offset: 0
+                }
+            },
+            AttributeDesc {
+                location: 1,
+                binding: 0,
+                element: Element {
+                    format: Format::Rgb32Sfloat,
+                    offset: mem::size_of::<[f32; 3]>() as u32
+                }
+            }
+        ]
+    }
+}
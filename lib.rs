@@ -0,0 +1,6 @@
+// The Engine API, Shared by the Sample in main.rs
+pub mod graphics;
+pub mod frame_pacer;
+pub mod frame_timer;
+pub mod cursor_grab;
+pub mod window_config;
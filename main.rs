@@ -1,47 +1,82 @@
 
+use std::time::{Duration, Instant};
+
 use winit::{
     event_loop::{EventLoop, ControlFlow},
-    event::{Event, WindowEvent},
-    window::WindowBuilder
+    event::{Event, WindowEvent, ElementState, MouseButton, VirtualKeyCode}
 };
 
-mod graphics;
-use graphics::Renderer;
+use rust_engine::{
+    graphics::{Renderer, RendererConfig, ShaderSource, Vertex},
+    frame_pacer::FramePacer,
+    frame_timer::FrameTimer,
+    cursor_grab::CursorGrab,
+    window_config::{WindowConfig, FullscreenToggle}
+};
 
 fn main() {
     const APP_NAME: &'static str = "Rust Engine";
     const WINDOW_SIZE: [u32; 2] = [512, 512];
+    const TITLE_UPDATE_INTERVAL: Duration = Duration::from_secs(1);
 
     let event_loop = EventLoop::new();
 
-    let (logical_size, physical_size) = {
-        use winit::dpi::{LogicalSize, PhysicalSize};
+    let window_config = WindowConfig {
+        title: APP_NAME.to_string(),
+        size: WINDOW_SIZE,
+        ..WindowConfig::default()
+    };
 
-        let dpi = event_loop.primary_monitor().unwrap().scale_factor();
-        let logical: LogicalSize<u32> = WINDOW_SIZE.into();
-        let physical: PhysicalSize<u32> = logical.to_physical(dpi);
+    let (window, physical_size) = window_config.build(&event_loop);
 
-        (logical, physical)
-    };
+    let mut fullscreen_toggle = FullscreenToggle::new(window_config.fullscreen_toggle);
 
-    let window = WindowBuilder::new()
-        .with_title(APP_NAME)
-        .with_inner_size(logical_size)
-        .build(&event_loop)
-        .expect("Failed to create window");
+    // Clicking Captures the Cursor, Escape Releases It
+    let mut cursor_grab = CursorGrab::new();
 
-    let vertex_shader = include_str!("shaders/part-1.vert");
-    let fragment_shader = include_str!("shaders/part-1.frag");
+    // A Triangle with a Color at Each Corner
+    let vertices = [
+        Vertex { position: [0.0, -0.5, 0.0], color: [1.0, 0.0, 0.0] },
+        Vertex { position: [-0.5, 0.5, 0.0], color: [0.0, 1.0, 0.0] },
+        Vertex { position: [0.5, 0.5, 0.0], color: [0.0, 0.0, 1.0] }
+    ];
+    let indices = [0, 1, 2];
+
+    let vertex_shader = ShaderSource {
+        file_name: "shaders/part-1.vert",
+        text: include_str!("shaders/part-1.vert")
+    };
+    let fragment_shader = ShaderSource {
+        file_name: "shaders/part-1.frag",
+        text: include_str!("shaders/part-1.frag")
+    };
 
     let mut renderer = Renderer::<backend::Backend>::new(
         APP_NAME,
         physical_size.into(),
         &window,
         vertex_shader,
-        fragment_shader);
+        fragment_shader,
+        &vertices,
+        &indices,
+        RendererConfig {
+            pipeline_cache_path: Some(std::env::temp_dir().join("rust_engine_pipeline.cache")),
+            ..RendererConfig::default()
+        })
+        .unwrap_or_else(|error| {
+            eprintln!("Failed to create renderer: {}", error);
+            std::process::exit(1)
+        });
+
+    // Pace Frames to the Monitor's Refresh Rate
+    let mut frame_pacer = FramePacer::new(&window);
+
+    // Show the Frame Rate in the Title
+    let mut frame_timer = FrameTimer::new();
+    let mut last_title_update = Instant::now();
 
     event_loop.run(move |event, _, control_flow| {
-        *control_flow = ControlFlow::Poll;
+        *control_flow = ControlFlow::WaitUntil(frame_pacer.next_frame());
 
         // Handle Events
         match event {
@@ -56,14 +91,92 @@ fn main() {
                renderer.update_dimensions(new_size.into()),
 
             // The Logical Scale has Changed
-            Event::WindowEvent {event: WindowEvent::ScaleFactorChanged {new_inner_size, ..}, ..} =>
-               renderer.update_dimensions(new_inner_size.clone().into()),
+            // The Window May Now Be on a Different Monitor
+            Event::WindowEvent {event: WindowEvent::ScaleFactorChanged {new_inner_size, ..}, ..} => {
+               renderer.update_dimensions(new_inner_size.clone().into());
+               frame_pacer.update_monitor(&window);
+            },
+
+            // The Window has Moved, Possibly to a Different Monitor
+            Event::WindowEvent {event: WindowEvent::Moved(..), ..} =>
+               frame_pacer.update_monitor(&window),
+
+            // Track Modifier Keys for Key Bindings
+            Event::WindowEvent {event: WindowEvent::ModifiersChanged(modifiers), ..} =>
+               fullscreen_toggle.set_modifiers(modifiers),
+
+            // A Key was Pressed or Released
+            Event::WindowEvent {event: WindowEvent::KeyboardInput {input, ..}, ..} => {
+               let consumed = fullscreen_toggle.handle_key(&input, &window);
+
+               let is_escape = input.state == ElementState::Pressed
+                   && input.virtual_keycode == Some(VirtualKeyCode::Escape);
+
+               if !consumed && is_escape && cursor_grab.is_grabbed() {
+                   cursor_grab.set_grabbed(&window, false);
+               }
+            },
+
+            // The Left Mouse Button was Pressed
+            Event::WindowEvent {
+                event: WindowEvent::MouseInput {state: ElementState::Pressed, button: MouseButton::Left, ..},
+                ..
+            } if !cursor_grab.is_grabbed() =>
+               cursor_grab.set_grabbed(&window, true),
+
+            // Release the Cursor When Switching to Another Window
+            Event::WindowEvent {event: WindowEvent::Focused(false), ..} if cursor_grab.is_grabbed() =>
+               cursor_grab.set_grabbed(&window, false),
+
+            // Raw Input, Independent of the Cursor
+            Event::DeviceEvent {event, ..} =>
+               cursor_grab.handle_device_event(&event),
+
+            // The Platform May Invalidate the Surface While Suspended
+            Event::Suspended => renderer.suspend(),
+            Event::Resumed => renderer.resume(&window),
 
             // Execute Non-draw Logic
-            Event::MainEventsCleared => window.request_redraw(),
+            // Draw Once the Next Frame is Due
+            Event::MainEventsCleared => {
+                let now = Instant::now();
+
+                if frame_pacer.is_frame_due(now) {
+                    frame_pacer.advance(now);
+                    window.request_redraw();
+                }
+
+                *control_flow = ControlFlow::WaitUntil(frame_pacer.next_frame());
+            },
 
             // Execute Draw Logic
-            Event::RedrawRequested(..) => renderer.render(),
+            Event::RedrawRequested(..) => {
+                if let Err(error) = renderer.configure_swapchain() {
+                    eprintln!("Failed to configure swapchain: {}; stopping", error);
+                    *control_flow = ControlFlow::Exit;
+                    return;
+                }
+
+                // Skip Drawing While Minimized
+                if !renderer.is_renderable() {
+                    return;
+                }
+
+                let now = Instant::now();
+                frame_timer.tick(now);
+
+                if let Some(fps) = frame_timer.fps() {
+                    if now.duration_since(last_title_update) >= TITLE_UPDATE_INTERVAL {
+                        window.set_title(&format!("{} - {:.0} FPS", APP_NAME, fps));
+                        last_title_update = now;
+                    }
+                }
+
+                if let Err(error) = renderer.render() {
+                    eprintln!("Failed to render: {}; stopping", error);
+                    *control_flow = ControlFlow::Exit
+                }
+            },
 
             _ => ()
         }
@@ -2,8 +2,10 @@
 #version 450
 #extension GL_ARB_separate_shader_objects : enable
 
+layout(location = 0) in vec3 vertex_color;
+
 layout(location = 0) out vec4 fragment_color;
 
 void main() {
-    fragment_color = vec4(0.5, 0.5, 1.0, 1.0);
+    fragment_color = vec4(vertex_color, 1.0);
 }
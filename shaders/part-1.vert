@@ -2,13 +2,16 @@
 #version 450
 #extension GL_ARB_separate_shader_objects : enable
 
-vec2 positions[3] = vec2[](
-    vec2(0.0, -0.5),
-    vec2(-0.5, 0.5),
-    vec2(0.5, 0.5)
-);
+layout(push_constant) uniform PushConstants {
+    mat4 model;
+} push_constants;
+
+layout(location = 0) in vec3 position;
+layout(location = 1) in vec3 color;
+
+layout(location = 0) out vec3 vertex_color;
 
 void main() {
-    vec2 pos = positions[gl_VertexIndex];
-    gl_Position = vec4(pos, 0.0, 1.0);
+    vertex_color = color;
+    gl_Position = push_constants.model * vec4(position, 1.0);
 }
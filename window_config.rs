@@ -0,0 +1,250 @@
+
+use winit::{
+    dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize},
+    event::{ElementState, KeyboardInput, ModifiersState, VirtualKeyCode},
+    event_loop::EventLoop,
+    monitor::MonitorHandle,
+    window::{Fullscreen, Window, WindowBuilder}
+};
+
+/// How the Window Occupies its Monitor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FullscreenMode {
+    Windowed,
+
+    /// Cover the Monitor Without Changing its Video Mode.
+    Borderless,
+
+    /// Take Over the Monitor at its Largest, Fastest Video Mode.
+    Exclusive
+}
+
+/// Settings Used When Creating the Window.
+#[derive(Debug, Clone)]
+pub struct WindowConfig {
+    pub title: String,
+
+    /// Inner Size in Logical Pixels, Used When Windowed.
+    pub size: [u32; 2],
+
+    /// Position in Logical Pixels Relative to the Monitor's Top Left Corner.
+    /// `None` centers the window on `monitor` if one is given, and otherwise
+    /// lets the platform decide. Ignored when fullscreen.
+    pub position: Option<[i32; 2]>,
+
+    pub fullscreen: FullscreenMode,
+
+    /// Index of the Monitor to Open On.
+    /// `None`, or an index that doesn't exist, uses the primary monitor.
+    pub monitor: Option<usize>,
+
+    pub always_on_top: bool,
+    pub decorations: bool,
+
+    /// Let the Desktop Show Through Wherever the Surface's Alpha is Below 1.0.
+    /// The renderer must also be given a non-opaque composite alpha mode.
+    pub transparent: bool,
+
+    /// Keys that Switch Between Windowed and Borderless Fullscreen.
+    /// `None` disables the toggle.
+    pub fullscreen_toggle: Option<KeyBinding>
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            size: [512, 512],
+            position: None,
+            fullscreen: FullscreenMode::Windowed,
+            monitor: None,
+            always_on_top: false,
+            decorations: true,
+            transparent: false,
+            fullscreen_toggle: Some(KeyBinding {
+                key: VirtualKeyCode::Return,
+                modifiers: ModifiersState::ALT
+            })
+        }
+    }
+}
+
+impl WindowConfig {
+    /// Create the Window and Return it with its Inner Size in Physical Pixels.
+    pub fn build<T>(&self, event_loop: &EventLoop<T>) -> (Window, PhysicalSize<u32>) {
+        let monitor = self.select_monitor(event_loop);
+
+        let logical_size: LogicalSize<u32> = self.size.into();
+
+        let monitor_size = || monitor.as_ref().map_or_else(
+            || logical_size.to_physical(1.0),
+            |m| m.size());
+
+        // Fullscreen Windows Match the Monitor or Video Mode Instead of the Configured Size
+        let (fullscreen, physical_size) = match self.fullscreen {
+            FullscreenMode::Windowed => {
+                let scale_factor = monitor.as_ref().map_or(1.0, |m| m.scale_factor());
+
+                (None, logical_size.to_physical(scale_factor))
+            },
+            FullscreenMode::Borderless =>
+                (Some(Fullscreen::Borderless(monitor.clone())), monitor_size()),
+            FullscreenMode::Exclusive => {
+                let video_mode = monitor.as_ref().and_then(|m| {
+                    m.video_modes().max_by_key(|mode| {
+                        let size = mode.size();
+                        (size.width * size.height, mode.refresh_rate())
+                    })
+                });
+
+                match video_mode {
+                    Some(video_mode) => {
+                        let size = video_mode.size();
+                        (Some(Fullscreen::Exclusive(video_mode)), size)
+                    },
+                    None => {
+                        eprintln!("Warning: no video mode available for exclusive fullscreen; \
+                            using borderless fullscreen");
+
+                        (Some(Fullscreen::Borderless(monitor.clone())), monitor_size())
+                    }
+                }
+            }
+        };
+
+        // Stay Hidden Until Positioned to Avoid a Visible Jump
+        let window = WindowBuilder::new()
+            .with_title(self.title.clone())
+            .with_inner_size(logical_size)
+            .with_fullscreen(fullscreen)
+            .with_always_on_top(self.always_on_top)
+            .with_decorations(self.decorations)
+            .with_transparent(self.transparent)
+            .with_visible(false)
+            .build(event_loop)
+            .expect("Failed to create window");
+
+        if let (FullscreenMode::Windowed, Some(monitor)) = (self.fullscreen, &monitor) {
+            let offset = match self.position {
+                Some(position) => {
+                    let offset: LogicalPosition<i32> = position.into();
+                    Some(offset.to_physical(monitor.scale_factor()))
+                },
+
+                // Center on an Explicitly Chosen Monitor
+                // Otherwise the platform may open the window on a different one.
+                None if self.monitor.is_some() => {
+                    let monitor_size = monitor.size();
+                    let window_size = window.outer_size();
+
+                    Some(PhysicalPosition::new(
+                        (monitor_size.width as i32 - window_size.width as i32) / 2,
+                        (monitor_size.height as i32 - window_size.height as i32) / 2))
+                },
+
+                None => None
+            };
+
+            if let Some(offset) = offset {
+                let origin = monitor.position();
+
+                window.set_outer_position(PhysicalPosition::new(
+                    origin.x + offset.x,
+                    origin.y + offset.y));
+            }
+        }
+
+        window.set_visible(true);
+
+        (window, physical_size)
+    }
+
+    /// Find the Configured Monitor, Falling Back to the Primary Monitor.
+    fn select_monitor<T>(&self, event_loop: &EventLoop<T>) -> Option<MonitorHandle> {
+        let requested = self.monitor.and_then(|index| {
+            let monitor = event_loop.available_monitors().nth(index);
+
+            if monitor.is_none() {
+                eprintln!("Warning: monitor {} does not exist; using the primary monitor", index);
+            }
+
+            monitor
+        });
+
+        requested
+            .or_else(|| event_loop.primary_monitor())
+            .or_else(|| event_loop.available_monitors().next())
+    }
+}
+
+/// A Key Pressed Together with a Set of Modifiers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeyBinding {
+    pub key: VirtualKeyCode,
+    pub modifiers: ModifiersState
+}
+
+/// Switches the Window Between Windowed and Borderless Fullscreen,
+/// Restoring the Windowed Size and Position When Leaving Fullscreen.
+///
+/// The resulting `Resized` events reconfigure the swapchain as usual.
+pub struct FullscreenToggle {
+    binding: Option<KeyBinding>,
+    modifiers: ModifiersState,
+
+    // Saved Before Entering Fullscreen
+    windowed_placement: Option<(PhysicalPosition<i32>, PhysicalSize<u32>)>
+}
+
+impl FullscreenToggle {
+    pub fn new(binding: Option<KeyBinding>) -> Self {
+        Self {
+            binding,
+            modifiers: ModifiersState::empty(),
+            windowed_placement: None
+        }
+    }
+
+    /// Track the Held Modifiers from `WindowEvent::ModifiersChanged`.
+    pub fn set_modifiers(&mut self, modifiers: ModifiersState) {
+        self.modifiers = modifiers;
+    }
+
+    /// Toggle Fullscreen if the Input Matches the Binding.
+    /// Returns True if the Input was Consumed.
+    pub fn handle_key(&mut self, input: &KeyboardInput, window: &Window) -> bool {
+        let binding = match self.binding {
+            Some(binding) => binding,
+            None => return false
+        };
+
+        let is_match = input.state == ElementState::Pressed
+            && input.virtual_keycode == Some(binding.key)
+            && self.modifiers == binding.modifiers;
+
+        if is_match {
+            self.toggle(window);
+        }
+
+        is_match
+    }
+
+    pub fn toggle(&mut self, window: &Window) {
+        if window.fullscreen().is_some() {
+            window.set_fullscreen(None);
+
+            if let Some((position, size)) = self.windowed_placement.take() {
+                window.set_outer_position(position);
+                window.set_inner_size(size);
+            }
+        } else {
+            // Not All Platforms Report a Position
+            self.windowed_placement = window
+                .outer_position()
+                .ok()
+                .map(|position| (position, window.inner_size()));
+
+            window.set_fullscreen(Some(Fullscreen::Borderless(window.current_monitor())));
+        }
+    }
+}